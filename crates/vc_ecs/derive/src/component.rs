@@ -173,10 +173,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     } else if let Some(behavior) = attrs.clone_behavior {
         quote!(#vc_ecs_path::component::ComponentCloneBehavior::#behavior)
     } else {
-        quote!(
-            use #vc_ecs_path::component::{DefaultCloneBehaviorBase, DefaultCloneBehaviorViaClone};
-            (&&&#vc_ecs_path::component::DefaultCloneBehaviorSpecialization::<Self>::default()).default_clone_behavior()
-        )
+        quote!(#vc_ecs_path::component::ComponentCloneBehavior::Default)
     };
 
     let relationship_accessor = if (relationship.is_some() || relationship_target.is_some())
@@ -196,12 +193,12 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                         #vc_ecs_path::relationship::ComponentRelationshipAccessor::<Self>::relationship(
                             core::mem::offset_of!(Self, #relationship_member)
                         )
-                    }
+                    }.accessor
                 )
             }
         } else {
             quote! {
-                Some(#vc_ecs_path::relationship::ComponentRelationshipAccessor::<Self>::relationship_target())
+                Some(#vc_ecs_path::relationship::ComponentRelationshipAccessor::<Self>::relationship_target().accessor)
             }
         }
     } else {
@@ -213,7 +210,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         #required_component_docs
         impl #impl_generics #vc_ecs_path::component::Component for #struct_name #type_generics #where_clause {
-            const STORAGE_TYPE: #vc_ecs_path::component::StorageType = #storage;
+            const STORAGE_TYPE: #vc_ecs_path::storage::StorageType = #storage;
             type Mutability = #mutable_type;
             fn register_required_components(
                 _requiree: #vc_ecs_path::component::ComponentId,
@@ -234,7 +231,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 
             #map_entities
 
-            fn relationship_accessor() -> Option<#vc_ecs_path::relationship::ComponentRelationshipAccessor<Self>> {
+            fn relationship_accessor() -> Option<#vc_ecs_path::relationship::RelationshipAccessor> {
                 #relationship_accessor
             }
         }
@@ -677,7 +674,7 @@ fn storage_path(vc_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
         StorageTy::SparseSet => Ident::new("SparseSet", Span::call_site()),
     };
 
-    quote! { #vc_ecs_path::component::StorageType::#storage_type }
+    quote! { #vc_ecs_path::storage::StorageType::#storage_type }
 }
 
 fn hook_register_function_call(
@@ -1,5 +1,6 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
 use syn::{
     Data, DataStruct, DeriveInput, Fields, Index, Member, Path, Result, Token, Type,
     parse_macro_input, parse_quote, spanned::Spanned,
@@ -57,19 +58,22 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
     })
 }
 
-pub fn derive_entity_event(input: TokenStream) -> TokenStream {
-    let mut ast = parse_macro_input!(input as DeriveInput);
+/// The parsed `#[entity_event(...)]` attributes.
+#[derive(Default)]
+struct EntityEventAttrs {
+    auto_propagate: bool,
+    propagate: bool,
+    traversal: Option<Type>,
+    trigger: Option<Type>,
+}
 
-    ast.generics
-        .make_where_clause()
-        .predicates
-        .push(parse_quote! { Self: Send + Sync + 'static });
-
-    let mut auto_propagate = false;
-    let mut propagate = false;
-    let mut traversal: Option<Type> = None;
-    let mut trigger: Option<Type> = None;
-    let vc_ecs_path: Path = crate::path::vc_ecs_path();
+/// Parses the `#[entity_event(...)]` attributes, rejecting invalid combinations.
+fn parse_entity_event_attrs(ast: &DeriveInput) -> Result<EntityEventAttrs> {
+    let mut attrs = EntityEventAttrs::default();
+    // Used to report a conflict between `trigger` and the attribute that enabled propagation.
+    let mut propagate_attr: Option<&str> = None;
+    let mut trigger_span: Option<Span> = None;
+    let mut auto_propagate_span: Option<Span> = None;
 
     let mut processed_attrs = Vec::new();
 
@@ -78,45 +82,80 @@ pub fn derive_entity_event(input: TokenStream) -> TokenStream {
         .iter()
         .filter(|attr| attr.path().is_ident(ENTITY_EVENT))
     {
-        if let Err(e) = attr.parse_nested_meta(|meta| match meta.path.get_ident() {
+        attr.parse_nested_meta(|meta| match meta.path.get_ident() {
             Some(ident) if processed_attrs.iter().any(|i| ident == i) => {
                 Err(meta.error(format!("duplicate attribute: {ident}")))
             }
             Some(ident) if ident == AUTO_PROPAGATE => {
-                propagate = true;
-                auto_propagate = true;
+                attrs.auto_propagate = true;
+                auto_propagate_span = Some(ident.span());
+                propagate_attr.get_or_insert(AUTO_PROPAGATE);
                 processed_attrs.push(AUTO_PROPAGATE);
                 Ok(())
             }
             Some(ident) if ident == PROPAGATE => {
-                propagate = true;
+                attrs.propagate = true;
+                propagate_attr.get_or_insert(PROPAGATE);
                 if meta.input.peek(Token![=]) {
-                    traversal = Some(meta.value()?.parse()?);
+                    attrs.traversal = Some(meta.value()?.parse()?);
                 }
                 processed_attrs.push(PROPAGATE);
                 Ok(())
             }
             Some(ident) if ident == TRIGGER => {
-                trigger = Some(meta.value()?.parse()?);
+                trigger_span = Some(ident.span());
+                attrs.trigger = Some(meta.value()?.parse()?);
                 processed_attrs.push(TRIGGER);
                 Ok(())
             }
             Some(ident) => Err(meta.error(format!("unsupported attribute: {ident}"))),
             None => Err(meta.error("expected identifier")),
-        }) {
-            return e.to_compile_error().into();
-        }
+        })?;
     }
 
-    if trigger.is_some() && propagate {
-        return syn::Error::new(
-            ast.span(),
-            "Cannot define both #[entity_event(trigger)] and #[entity_event(propagate)]",
-        )
-        .into_compile_error()
-        .into();
+    if let (Some(trigger_span), Some(propagate_attr)) = (trigger_span, propagate_attr) {
+        return Err(syn::Error::new(
+            trigger_span,
+            format!(
+                "Cannot define both #[entity_event(trigger)] and #[entity_event({propagate_attr})], \
+                propagation already selects the trigger"
+            ),
+        ));
+    }
+
+    if let Some(span) = auto_propagate_span
+        && !attrs.propagate
+    {
+        return Err(syn::Error::new(
+            span,
+            "#[entity_event(auto_propagate)] requires a propagation target, \
+            add #[entity_event(propagate)] or #[entity_event(propagate = Traversal)]",
+        ));
     }
 
+    Ok(attrs)
+}
+
+pub fn derive_entity_event(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+
+    ast.generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { Self: Send + Sync + 'static });
+
+    let vc_ecs_path: Path = crate::path::vc_ecs_path();
+
+    let EntityEventAttrs {
+        auto_propagate,
+        propagate,
+        traversal,
+        trigger,
+    } = match parse_entity_event_attrs(&ast) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let entity_field = match get_event_target_field(&ast) {
         Ok(value) => value,
         Err(err) => return err.into_compile_error().into(),
@@ -125,6 +164,19 @@ pub fn derive_entity_event(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
+    // An explicit traversal is checked on its own, so a type that is not a
+    // `Traversal` is reported at the attribute instead of deep in the trigger.
+    let traversal_check = traversal.as_ref().map(|traversal| {
+        quote_spanned! {traversal.span()=>
+            const _: () = {
+                fn assert_traversal #impl_generics () #where_clause {
+                    fn check<T: #vc_ecs_path::traversal::Traversal<E>, E>() {}
+                    check::<#traversal, #struct_name #type_generics>();
+                }
+            };
+        }
+    });
+
     let trigger = if let Some(trigger) = trigger {
         quote! {#trigger}
     } else if propagate {
@@ -159,6 +211,8 @@ pub fn derive_entity_event(input: TokenStream) -> TokenStream {
         }
 
         #set_entity_event_target_impl
+
+        #traversal_check
     })
 }
 
@@ -206,3 +260,66 @@ fn get_event_target_field(ast: &DeriveInput) -> Result<Member> {
         )),
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use syn::{DeriveInput, parse_quote};
+
+    use super::parse_entity_event_attrs;
+
+    fn error(ast: DeriveInput) -> String {
+        match parse_entity_event_attrs(&ast) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn valid_combinations() {
+        let ast: DeriveInput = parse_quote! {
+            #[entity_event(propagate, auto_propagate)]
+            struct Clicked(Entity);
+        };
+        let attrs = parse_entity_event_attrs(&ast).unwrap();
+        assert!(attrs.propagate && attrs.auto_propagate);
+        assert!(attrs.traversal.is_none());
+
+        let ast: DeriveInput = parse_quote! {
+            #[entity_event(propagate = &'static Parent)]
+            struct Clicked(Entity);
+        };
+        let attrs = parse_entity_event_attrs(&ast).unwrap();
+        assert!(attrs.propagate && !attrs.auto_propagate);
+        assert_eq!(attrs.traversal, Some(parse_quote! { &'static Parent }));
+
+        let ast: DeriveInput = parse_quote! {
+            #[entity_event(trigger = CustomTrigger)]
+            struct Clicked(Entity);
+        };
+        assert!(parse_entity_event_attrs(&ast).unwrap().trigger.is_some());
+    }
+
+    #[test]
+    fn auto_propagate_requires_target() {
+        let message = error(parse_quote! {
+            #[entity_event(auto_propagate)]
+            struct Clicked(Entity);
+        });
+        assert!(
+            message.contains("requires a propagation target"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn trigger_conflicts_with_propagation() {
+        let message = error(parse_quote! {
+            #[entity_event(propagate, trigger = CustomTrigger)]
+            struct Clicked(Entity);
+        });
+        assert!(message.contains("Cannot define both"), "{message}");
+    }
+}
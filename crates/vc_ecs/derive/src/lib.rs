@@ -68,6 +68,54 @@ impl Default for BundleAttributes {
     }
 }
 
+/// Reads the `#[bundle(...)]` attributes of a bundle field.
+fn bundle_field_kind(field: &syn::Field) -> syn::Result<BundleFieldKind> {
+    let mut kind = BundleFieldKind::Component;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(BUNDLE_ATTRIBUTE_NAME))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(BUNDLE_ATTRIBUTE_IGNORE_NAME) {
+                kind = BundleFieldKind::Ignore;
+                Ok(())
+            } else {
+                Err(meta.error(format!(
+                    "Invalid bundle attribute. Use `{BUNDLE_ATTRIBUTE_IGNORE_NAME}`"
+                )))
+            }
+        })?;
+    }
+
+    Ok(kind)
+}
+
+/// Rejects a component type used by more than one non-ignored field.
+///
+/// Best effort: types are compared syntactically, so aliases are not detected.
+fn check_duplicate_bundle_types(fields: &syn::Fields) -> syn::Result<()> {
+    let mut seen_types = Vec::with_capacity(fields.len());
+    for field in fields {
+        if let BundleFieldKind::Ignore = bundle_field_kind(field)? {
+            continue;
+        }
+        let key = field.ty.to_token_stream().to_string();
+        if seen_types.contains(&key) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "duplicate type `{key}` in bundle, each component can only appear once. \
+                    Use `#[{BUNDLE_ATTRIBUTE_NAME}({BUNDLE_ATTRIBUTE_IGNORE_NAME})]` to exclude a field"
+                ),
+            ));
+        }
+        seen_types.push(key);
+    }
+    Ok(())
+}
+
 /// Implement the `Bundle` trait.
 #[proc_macro_derive(Bundle, attributes(bundle))]
 pub fn derive_bundle(input: TokenStream) -> TokenStream {
@@ -102,28 +150,14 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
     let mut field_kinds = Vec::with_capacity(fields.len());
 
     for field in fields {
-        let mut kind = BundleFieldKind::Component;
-
-        for attr in field
-            .attrs
-            .iter()
-            .filter(|a| a.path().is_ident(BUNDLE_ATTRIBUTE_NAME))
-        {
-            if let Err(error) = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident(BUNDLE_ATTRIBUTE_IGNORE_NAME) {
-                    kind = BundleFieldKind::Ignore;
-                    Ok(())
-                } else {
-                    Err(meta.error(format!(
-                        "Invalid bundle attribute. Use `{BUNDLE_ATTRIBUTE_IGNORE_NAME}`"
-                    )))
-                }
-            }) {
-                return error.into_compile_error().into();
-            }
+        match bundle_field_kind(field) {
+            Ok(kind) => field_kinds.push(kind),
+            Err(error) => return error.into_compile_error().into(),
         }
+    }
 
-        field_kinds.push(kind);
+    if let Err(error) = check_duplicate_bundle_types(fields) {
+        return error.into_compile_error().into();
     }
 
     let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
@@ -146,6 +180,7 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
             BundleFieldKind::Ignore => inactive_field_members.push(field_member),
         }
     }
+
     let generics = ast.generics;
     let generics_ty_list = generics.type_params().map(|p| p.ident.clone());
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -578,7 +613,7 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 /// #[entity_event(propagate)]
 /// /// Enable propagation using the given Traversal implementation
 /// #[entity_event(propagate = &'static ChildOf)]
-/// /// Always propagate
+/// /// Always propagate, requires `propagate` to select the traversal
 /// #[entity_event(auto_propagate)]
 /// struct MyEvent;
 /// ```
@@ -588,11 +623,32 @@ pub fn derive_entity_event(input: TokenStream) -> TokenStream {
 }
 
 /// Implement the `Message` trait.
-#[proc_macro_derive(Message)]
+///
+/// Cheat sheet for derive syntax,
+/// see full explanation on `Message` trait docs.
+///
+/// ```ignore
+/// #[derive(Message)]
+/// /// Readers borrow the message instead of taking ownership, sets `Message::SHARED`
+/// #[message(shared)]
+/// struct MyMessage;
+/// ```
+#[proc_macro_derive(Message, attributes(message))]
 pub fn derive_message(input: TokenStream) -> TokenStream {
     message::derive_message(input)
 }
 
+/// Implement the `Component` trait.
+///
+/// Cheat sheet for derive syntax,
+/// see full explanation on `Component` trait docs.
+///
+/// ```ignore
+/// #[derive(Component)]
+/// /// Sets `Component::STORAGE_TYPE`, either "Table" (the default) or "SparseSet"
+/// #[component(storage = "SparseSet")]
+/// struct MyTag;
+/// ```
 #[proc_macro_derive(
     Component,
     attributes(component, require, relationship, relationship_target, entities)
@@ -660,3 +716,30 @@ pub fn derive_from_world(input: TokenStream) -> TokenStream {
             }
     })
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use syn::{FieldsNamed, parse_quote};
+
+    use super::check_duplicate_bundle_types;
+
+    #[test]
+    fn duplicate_bundle_types() {
+        let fields: FieldsNamed = parse_quote! {{ a: Position, b: Position }};
+        let error = check_duplicate_bundle_types(&fields.into()).unwrap_err();
+        assert!(error.to_string().contains("duplicate type `Position`"));
+
+        let fields: FieldsNamed = parse_quote! {{
+            a: Position,
+            #[bundle(ignore)]
+            b: Position,
+        }};
+        assert!(check_duplicate_bundle_types(&fields.into()).is_ok());
+
+        let fields: FieldsNamed = parse_quote! {{ a: Position, b: Velocity }};
+        assert!(check_duplicate_bundle_types(&fields.into()).is_ok());
+    }
+}
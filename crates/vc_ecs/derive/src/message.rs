@@ -2,6 +2,9 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, Path, parse_macro_input, parse_quote};
 
+pub const MESSAGE: &str = "message";
+pub const SHARED: &str = "shared";
+
 pub fn derive_message(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let vc_ecs_path: Path = crate::path::vc_ecs_path();
@@ -11,10 +14,39 @@ pub fn derive_message(input: TokenStream) -> TokenStream {
         .predicates
         .push(parse_quote! { Self: Send + Sync + 'static });
 
+    let mut processed_attrs = Vec::new();
+    let mut shared = false;
+
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(MESSAGE))
+    {
+        if let Err(e) = attr.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) if processed_attrs.iter().any(|i| ident == i) => {
+                Err(meta.error(format!("duplicate attribute: {ident}")))
+            }
+            Some(ident) if ident == SHARED => {
+                shared = true;
+                processed_attrs.push(SHARED);
+                Ok(())
+            }
+            Some(ident) => Err(meta.error(format!("unsupported attribute: {ident}"))),
+            None => Err(meta.error("expected identifier")),
+        }) {
+            return e.to_compile_error().into();
+        }
+    }
+
+    // Only emitted when set, the trait defaults to the owned-drain mode.
+    let shared = shared.then(|| quote! { const SHARED: bool = true; });
+
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
     TokenStream::from(quote! {
-        impl #impl_generics #vc_ecs_path::message::Message for #struct_name #type_generics #where_clause {}
+        impl #impl_generics #vc_ecs_path::message::Message for #struct_name #type_generics #where_clause {
+            #shared
+        }
     })
 }
@@ -28,6 +28,19 @@ pub struct Archetype {
 }
 
 impl Archetype {
+    /// Creates the archetype without any components, stored in [`TableId::EMPTY`].
+    pub(crate) fn empty() -> Self {
+        Self {
+            id: ArchetypeId::EMPTY,
+            edges: Edges::empty(),
+            flags: ArchetypeFlags::empty(),
+            table_id: TableId::EMPTY,
+            entities: Vec::new(),
+            component_ids: Box::new([]),
+            storage_indecies: SparseHashMap::new(),
+        }
+    }
+
     #[inline(always)]
     pub fn id(&self) -> ArchetypeId {
         self.id
@@ -38,6 +51,8 @@ impl Archetype {
         self.flags
     }
 
+    /// Returns the id of the [`Table`](crate::storage::Table) storing the
+    /// table components of this archetype.
     #[inline(always)]
     pub fn table_id(&self) -> TableId {
         self.table_id
@@ -53,6 +68,14 @@ impl Archetype {
         &mut self.edges
     }
 
+    /// Returns the [`TableRow`] of the entity stored at `row` in this archetype.
+    ///
+    /// Archetype rows and table rows are not interchangeable: a table can be
+    /// shared by several archetypes that differ only in sparse-set components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
     #[inline]
     pub fn entity_table_row(&self, row: ArchetypeRow) -> TableRow {
         self.entities[row.index()].table_row
@@ -63,6 +86,20 @@ impl Archetype {
         self.entities[row.index()].table_row = table_row;
     }
 
+    /// Returns the [`ArchetypeRow`] of the entity stored at `table_row`, or `None`
+    /// if that table row does not belong to this archetype.
+    ///
+    /// This is the reverse of [`Archetype::entity_table_row`]. It scans the
+    /// archetype's entity list, so prefer [`EntityLocation`] when it is at hand.
+    pub fn table_row_archetype_row(&self, table_row: TableRow) -> Option<ArchetypeRow> {
+        let index = self
+            .entities
+            .iter()
+            .position(|entity| entity.table_row == table_row)?;
+        // SAFETY: an archetype can not hold more than `u32::MAX` entities.
+        Some(unsafe { ArchetypeRow::new(NonMaxU32::new_unchecked(index as u32)) })
+    }
+
     #[inline(always)]
     pub fn entities(&self) -> &[ArchetypeEntity] {
         &self.entities
@@ -121,6 +158,21 @@ impl Archetype {
         })
     }
 
+    /// Returns the ids of the components stored in this archetype's table.
+    ///
+    /// See [`Archetype::iter_table_components`] for their column indices.
+    #[inline]
+    pub fn table_components(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.iter_table_components().map(|(id, _)| id)
+    }
+
+    /// Returns the ids of the components stored in sparse sets,
+    /// which are not part of this archetype's table.
+    #[inline]
+    pub fn sparse_set_components(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.iter_sparse_set_components().map(|(id, _)| id)
+    }
+
     #[inline]
     pub fn components(&self) -> &[ComponentId] {
         &self.component_ids
@@ -268,3 +320,73 @@ impl Archetype {
         self.flags().contains(ArchetypeFlags::ON_DESPAWN_OBSERVER)
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use nonmax::NonMaxU32;
+
+    use super::Archetype;
+    use crate::archetype::ArchetypeRow;
+    use crate::component::ComponentId;
+    use crate::entity::{Entity, EntityId};
+    use crate::storage::{StorageIndex, StorageType, TableId, TableRow};
+
+    fn table_row(row: u32) -> TableRow {
+        TableRow::new(NonMaxU32::new(row).unwrap())
+    }
+
+    fn archetype_row(row: u32) -> ArchetypeRow {
+        ArchetypeRow::new(NonMaxU32::new(row).unwrap())
+    }
+
+    #[test]
+    fn archetype_and_table_rows_round_trip() {
+        let mut archetype = Archetype::empty();
+        for (index, row) in [5, 2, 9].into_iter().enumerate() {
+            let id = EntityId::new(NonZeroU32::new(index as u32 + 1).unwrap());
+            let location = unsafe { archetype.allocate(Entity::from_id(id), table_row(row)) };
+            assert_eq!(location.archetype_row, archetype_row(index as u32));
+        }
+
+        assert_eq!(archetype.entity_table_row(archetype_row(1)), table_row(2));
+        assert_eq!(
+            archetype.table_row_archetype_row(table_row(9)),
+            Some(archetype_row(2)),
+        );
+        assert_eq!(archetype.table_row_archetype_row(table_row(0)), None);
+
+        // The last entity is swapped into the removed row.
+        archetype.swap_remove(archetype_row(0));
+        assert_eq!(
+            archetype.table_row_archetype_row(table_row(9)),
+            Some(archetype_row(0)),
+        );
+        assert_eq!(archetype.table_row_archetype_row(table_row(5)), None);
+    }
+
+    #[test]
+    fn table_and_sparse_set_components() {
+        let table_id = TableId::new(1);
+        let position = ComponentId::new(NonZeroU32::new(1).unwrap());
+        let marker = ComponentId::new(NonZeroU32::new(2).unwrap());
+
+        let mut archetype = Archetype::empty();
+        archetype.table_id = table_id;
+        archetype.component_ids = [position, marker].into();
+        archetype
+            .storage_indecies
+            .insert(position, StorageIndex::new(StorageType::Table, 0));
+        archetype
+            .storage_indecies
+            .insert(marker, StorageIndex::new(StorageType::SparseSet, 0));
+
+        assert_ne!(archetype.table_id(), TableId::EMPTY);
+        assert!(archetype.table_components().eq([position]));
+        assert!(archetype.sparse_set_components().eq([marker]));
+    }
+}
@@ -23,11 +23,36 @@ pub struct Archetypes {
 }
 
 impl Archetypes {
+    /// Creates the archetype list, containing only the empty archetype.
+    pub fn empty() -> Self {
+        let mut precise_map = HashMap::new();
+        precise_map.insert(
+            ArchetypeComponents {
+                table_components: Box::new([]),
+                sparse_set_components: Box::new([]),
+            },
+            ArchetypeId::EMPTY,
+        );
+
+        Self {
+            archetypes: alloc::vec![Archetype::empty()],
+            precise_map,
+            rough_table: Vec::new(),
+            rough_map: SparseArray::empty(),
+        }
+    }
+
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.archetypes.len()
     }
 
+    /// Returns the [`ArchetypeId::EMPTY`] archetype, which has no components.
+    #[inline]
+    pub fn empty_archetype(&self) -> &Archetype {
+        &self.archetypes[ArchetypeId::EMPTY.index()]
+    }
+
     #[inline]
     pub fn get(&self, id: ArchetypeId) -> Option<&Archetype> {
         self.archetypes.get(id.index())
@@ -9,6 +9,9 @@ use core::hash;
 pub struct ArchetypeId(u32);
 
 impl ArchetypeId {
+    /// The archetype of entities without components.
+    ///
+    /// It always exists, spawned-empty entities are placed here.
     pub const EMPTY: ArchetypeId = ArchetypeId(0);
 
     #[inline(always)]
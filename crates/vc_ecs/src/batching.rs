@@ -1,3 +1,7 @@
+use alloc::vec::Vec;
+
+use vc_task::{ParallelSlice, TaskPool};
+
 #[derive(Clone, Debug)]
 pub struct BatchingStrategy {
     min_size_limit: usize,
@@ -81,3 +85,74 @@ impl BatchingStrategy {
         batch_size.clamp(self.min_size_limit, self.max_size_limit)
     }
 }
+
+/// Maps `items` in parallel chunks, then reduces the chunk results in chunk order.
+///
+/// The chunk size is computed by `batching` from the number of items and
+/// the threads of `task_pool`. Each chunk is mapped on the pool, but the
+/// results are collected by chunk index and `reduce` is applied sequentially
+/// from the first chunk to the last, whatever order the chunks finished in.
+///
+/// Combining results as chunks complete would make non-associative
+/// operations, such as floating-point addition, depend on scheduling.
+/// Here the result only depends on the chunk boundaries, so repeated runs
+/// are bit-identical. To get the same result on machines with a different
+/// thread count, use a [`BatchingStrategy::fixed`] batch size.
+///
+/// Returns `None` if `items` is empty.
+pub fn par_map_reduce_ordered<T, R, Map, Reduce>(
+    task_pool: &TaskPool,
+    batching: &BatchingStrategy,
+    items: &[T],
+    map: Map,
+    reduce: Reduce,
+) -> Option<R>
+where
+    T: Sync,
+    R: Send + 'static,
+    Map: Fn(&[T]) -> R + Send + Sync,
+    Reduce: FnMut(R, R) -> R,
+{
+    if items.is_empty() {
+        return None;
+    }
+
+    let batch_size = batching
+        .calc_batch_size(|| items.len(), task_pool.thread_num())
+        .max(1);
+    let results: Vec<R> = items.par_chunk_map(task_pool, batch_size, |_, chunk| map(chunk));
+    results.into_iter().reduce(reduce)
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use vc_task::TaskPool;
+
+    use super::{BatchingStrategy, par_map_reduce_ordered};
+
+    #[test]
+    fn ordered_reduce_is_deterministic() {
+        let task_pool = TaskPool::new();
+        let batching = BatchingStrategy::fixed(64);
+        let values: Vec<f64> = (0..10_000).map(|i| 1.0 / (i as f64 + 1.0)).collect();
+
+        let sum = |chunk: &[f64]| chunk.iter().sum::<f64>();
+        let expected = values.chunks(64).map(sum).reduce(|a, b| a + b);
+
+        for _ in 0..8 {
+            let result = par_map_reduce_ordered(&task_pool, &batching, &values, sum, |a, b| a + b);
+            assert_eq!(result.map(f64::to_bits), expected.map(f64::to_bits));
+        }
+
+        let empty: &[f64] = &[];
+        assert_eq!(
+            par_map_reduce_ordered(&task_pool, &batching, empty, sum, |a, b| a + b),
+            None
+        );
+    }
+}
@@ -18,6 +18,16 @@ pub trait DetectChanges {
     /// use `this.is_changed() && !this.is_added()`.
     fn is_changed(&self) -> bool;
 
+    /// Returns `true` if this value [is changed](DetectChanges::is_changed)
+    /// and `f` returns `true` for it.
+    ///
+    /// `f` is only called for changed values, so an expensive predicate
+    /// is never evaluated for unchanged ones.
+    #[inline]
+    fn is_changed_and(&self, f: impl FnOnce(&Self) -> bool) -> bool {
+        self.is_changed() && f(self)
+    }
+
     /// Returns the change tick recording the time this data was most recently changed.
     ///
     /// Note that components and resources are also marked as changed upon insertion.
@@ -47,6 +57,19 @@ pub trait DetectChangesMut: DetectChanges {
     /// **Note**: This operation cannot be undone.
     fn set_changed(&mut self);
 
+    /// Flags this value as having been changed, like [`set_changed`](DetectChangesMut::set_changed),
+    /// and returns `true` if that moved the changed tick.
+    ///
+    /// It returns `false` if the value was already flagged in this run,
+    /// so repeated touches can skip redundant downstream work.
+    #[inline]
+    #[cfg_attr(any(debug_assertions, feature = "debug"), track_caller)]
+    fn set_changed_checked(&mut self) -> bool {
+        let before = self.changed_tick();
+        self.set_changed();
+        self.changed_tick() != before
+    }
+
     /// Flags this value as having been added.
     ///
     /// It is not normally necessary to call this method.
@@ -140,3 +163,64 @@ pub trait DetectChangesMut: DetectChanges {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{DetectChanges, DetectChangesMut};
+    use crate::component::{Mut, Ref};
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+
+    #[test]
+    fn is_changed_and_short_circuits() {
+        let caller = DebugLocation::caller();
+        let value = 5_u32;
+        let added = Tick::new(1);
+        let changed = Tick::new(1);
+
+        let unchanged = Ref::new(
+            &value,
+            &added,
+            &changed,
+            Tick::new(2),
+            Tick::new(3),
+            caller.as_ref(),
+        );
+        assert!(!unchanged.is_changed_and(|_| panic!("predicate called on an unchanged value")));
+
+        let changed = Tick::new(3);
+        let changed = Ref::new(
+            &value,
+            &added,
+            &changed,
+            Tick::new(2),
+            Tick::new(3),
+            caller.as_ref(),
+        );
+        assert!(changed.is_changed_and(|v| **v == 5));
+        assert!(!changed.is_changed_and(|v| **v == 6));
+    }
+
+    #[test]
+    fn set_changed_checked_once_per_run() {
+        let mut caller = DebugLocation::caller();
+        let mut value = 5_u32;
+        let mut added = Tick::new(1);
+        let mut changed = Tick::new(1);
+
+        let mut value = Mut::new(
+            &mut value,
+            &mut added,
+            &mut changed,
+            Tick::new(1),
+            Tick::new(2),
+            caller.as_mut(),
+        );
+        assert!(value.set_changed_checked());
+        assert!(!value.set_changed_checked());
+        assert_eq!(value.changed_tick(), Tick::new(2));
+    }
+}
@@ -1,5 +1,6 @@
 #![expect(unsafe_code, reason = "need ptr operation")]
 
+use core::alloc::Layout;
 use core::panic::Location;
 
 use vc_ptr::{Ptr, PtrMut};
@@ -22,15 +23,18 @@ use crate::utils::DebugLocation;
 /// Consumes itself and returns a Rust reference `&T` with the same lifetime via
 /// [`into_inner`](Self::into_inner).
 ///
-/// Creates a copy with unchanged lifetime via [`reborrow`](Self::reborrow).
+/// Creates a copy with unchanged lifetime via [`reborrow`](Self::reborrow) or [`Clone`].
 ///
 /// Obtains a shorter-lived inner reference `&T` via [`Deref::deref`] or [`AsRef::as_ref`].
 ///
 /// Transforms the contained type via [`map`], [`try_map`], or [`filter_map`],
 /// e.g., from `Res<'a, (i32, String)>` to `Res<'a, String>`.
 ///
-/// Converts to the generic component reference [`Ref`] via [`From`] or [`Into`].
+/// Converts to the generic component reference [`Ref`] via [`into_ref`], [`From`]
+/// or [`Into`], or without consuming itself via [`reborrow_ref`].
 ///
+/// [`into_ref`]: Self::into_ref
+/// [`reborrow_ref`]: Self::reborrow_ref
 /// [`map`]: Self::map
 /// [`try_map`]: Self::try_map
 /// [`filter_map`]: Self::filter_map
@@ -93,7 +97,7 @@ pub struct ResMut<'w, T: ?Sized + Resource> {
 /// Consumes itself and returns a Rust reference `&T` with the same lifetime via
 /// [`into_inner`](Self::into_inner).
 ///
-/// Creates a copy with unchanged lifetime via [`reborrow`](Self::reborrow).
+/// Creates a copy with unchanged lifetime via [`reborrow`](Self::reborrow) or [`Clone`].
 ///
 /// Obtains a shorter-lived inner reference `&T` via [`Deref::deref`] or [`AsRef::as_ref`].
 ///
@@ -168,7 +172,7 @@ pub struct NonSendMut<'w, T: ?Sized + 'static> {
 /// Consumes itself and returns a Rust reference `&T` with the same lifetime via
 /// [`into_inner`](Self::into_inner).
 ///
-/// Creates a copy with unchanged lifetime via [`reborrow`](Self::reborrow).
+/// Creates a copy with unchanged lifetime via [`reborrow`](Self::reborrow) or [`Clone`].
 ///
 /// Obtains a shorter-lived inner reference `&T` via [`Deref::deref`] or [`AsRef::as_ref`].
 ///
@@ -214,7 +218,8 @@ pub struct Ref<'w, T: ?Sized> {
 /// (Data may be modified through the returned reference, but not within the transformation
 /// closure itself.)
 ///
-/// Converts to the shared reference [`Ref`] via [`From`] or [`Into`].
+/// Converts to the shared reference [`Ref`] via [`From`] or [`Into`], or creates
+/// a borrowed [`Ref`] view via [`as_ref_mut_ticks`](Self::as_ref_mut_ticks).
 ///
 /// [`map_unchanged`]: Self::map_unchanged
 /// [`try_map_unchanged`]: Self::try_map_unchanged
@@ -241,6 +246,9 @@ pub struct Mut<'w, T: ?Sized> {
 pub struct MutUntyped<'w> {
     pub(crate) value: PtrMut<'w>,
     pub(crate) ticks: ComponentTicksMut<'w>,
+    /// The layout of the erased value, checked by [`downcast_mut`](Self::downcast_mut).
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    layout: Layout,
 }
 
 // -----------------------------------------------------------------------------
@@ -319,10 +327,8 @@ impl<'w, T: ?Sized> From<Mut<'w, T>> for Ref<'w, T> {
 impl<'w, T: ?Sized> From<Mut<'w, T>> for MutUntyped<'w> {
     #[inline(always)]
     fn from(value: Mut<'w, T>) -> Self {
-        MutUntyped {
-            value: value.value.into(),
-            ticks: value.ticks,
-        }
+        let layout = Layout::for_value::<T>(value.value);
+        MutUntyped::new(value.value.into(), value.ticks, layout)
     }
 }
 
@@ -431,6 +437,25 @@ impl_debug!(NonSend<'w, T>);
 impl_debug!(ResMut<'w, T> Resource);
 impl_debug!(Res<'w, T> Resource);
 
+// -----------------------------------------------------------------------------
+// impl_clone
+
+macro_rules! impl_clone {
+    ($name:ident < $( $generics:tt ),+ > $($traits:ident)?) => {
+        impl<$($generics),* : ?Sized $(+ $traits)?> Clone for $name<$($generics),*> {
+            /// Equivalent to [`reborrow`](Self::reborrow).
+            #[inline]
+            fn clone(&self) -> Self {
+                self.reborrow()
+            }
+        }
+    };
+}
+
+impl_clone!(Ref<'w, T>);
+impl_clone!(NonSend<'w, T>);
+impl_clone!(Res<'w, T> Resource);
+
 // -----------------------------------------------------------------------------
 // impl_ref_methods
 
@@ -515,6 +540,25 @@ impl_ref_methods!(Res<'w, T>, T, Resource);
 impl_ref_methods!(NonSend<'w, T>, T,);
 impl_ref_methods!(Ref<'w, T>, T,);
 
+impl<'w, T: ?Sized + Resource> Res<'w, T> {
+    /// Converts into the generic component reference [`Ref`].
+    ///
+    /// Equivalent to [`Ref::from`], the ticks are carried over unchanged.
+    #[inline(always)]
+    pub fn into_ref(self) -> Ref<'w, T> {
+        self.into()
+    }
+
+    /// Creates a [`Ref`] with the same lifetime and ticks, without consuming `self`.
+    ///
+    /// Useful for passing a resource to helpers that take [`Ref`],
+    /// or for projecting a field via [`Ref::map`].
+    #[inline]
+    pub fn reborrow_ref(&self) -> Ref<'w, T> {
+        self.reborrow().into_ref()
+    }
+}
+
 // -----------------------------------------------------------------------------
 // impl_mut_methods
 
@@ -755,6 +799,21 @@ impl_change_detection_mut_and_deref_mut!(NonSendMut<'w, T>, T,);
 // MutUntyped : Method Implementation
 
 impl<'w> MutUntyped<'w> {
+    /// Creates a handle to an erased value with the given `layout`.
+    ///
+    /// The layout is only stored with the `debug` cfg.
+    #[inline(always)]
+    pub(crate) fn new(value: PtrMut<'w>, ticks: ComponentTicksMut<'w>, layout: Layout) -> Self {
+        cfg::debug! {
+            if {
+                Self { value, ticks, layout }
+            } else {
+                let _ = layout;
+                Self { value, ticks }
+            }
+        }
+    }
+
     /// Consumes self and returns the inner [`PtrMut`].
     ///
     /// Marks the target as `changed` since a mutable handle is returned.
@@ -778,6 +837,8 @@ impl<'w> MutUntyped<'w> {
                 last_run: self.ticks.last_run,
                 this_run: self.ticks.this_run,
             },
+            #[cfg(any(debug_assertions, feature = "debug"))]
+            layout: self.layout,
         }
     }
 
@@ -838,6 +899,39 @@ impl<'w> MutUntyped<'w> {
             ticks: self.ticks,
         }
     }
+
+    /// Specifies the reference type and converts self to a [`Mut`],
+    /// checking the type as far as possible.
+    ///
+    /// This function does not set the change flag.
+    ///
+    /// With the `debug` cfg, this panics if the pointer is misaligned for `T`
+    /// or the layout of `T` differs from the layout of the erased value.
+    /// Otherwise it is the same as [`with_type`](Self::with_type).
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the erased pointee type for this [`MutUntyped`].
+    /// A matching layout does not prove that the type is correct.
+    #[inline]
+    #[cfg_attr(any(debug_assertions, feature = "debug"), track_caller)]
+    pub unsafe fn downcast_mut<T>(self) -> Mut<'w, T> {
+        cfg::debug! {
+            assert!(
+                self.value.is_aligned::<T>(),
+                "cannot downcast to `{}`: the pointer is not aligned to {}",
+                core::any::type_name::<T>(),
+                align_of::<T>(),
+            );
+            assert_eq!(
+                self.layout,
+                Layout::new::<T>(),
+                "cannot downcast to `{}`: its layout differs from the erased value",
+                core::any::type_name::<T>(),
+            );
+        }
+        unsafe { self.with_type::<T>() }
+    }
 }
 
 impl<'w> DetectChanges for MutUntyped<'w> {
@@ -981,6 +1075,25 @@ impl<'w, T: ?Sized> Mut<'w, T> {
         }
     }
 
+    /// Creates a [`Ref`] view with the same ticks, borrowing `self`.
+    ///
+    /// Unlike converting via [`From`], this does not consume the [`Mut`],
+    /// and unlike [`DerefMut`](core::ops::DerefMut), it does not mark
+    /// the target as changed.
+    #[inline]
+    pub fn as_ref_mut_ticks(&self) -> Ref<'_, T> {
+        Ref {
+            value: self.value,
+            ticks: ComponentTicksRef {
+                added: self.ticks.added,
+                changed: self.ticks.changed,
+                changed_by: self.ticks.changed_by.as_deref(),
+                last_run: self.ticks.last_run,
+                this_run: self.ticks.this_run,
+            },
+        }
+    }
+
     /// Internal function to set the ticks when this [`Mut`] is used by a system.
     ///
     /// Not intended for public use.
@@ -990,3 +1103,160 @@ impl<'w, T: ?Sized> Mut<'w, T> {
         self.ticks.this_run = this_run;
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{Mut, MutUntyped, Ref, Res};
+    use crate::change_detection::DetectChanges;
+    use crate::component::{ComponentTicksMut, ComponentTicksRef};
+    use crate::resource::Resource;
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+
+    struct Score(u32);
+    impl Resource for Score {}
+
+    struct Stats {
+        hits: u32,
+    }
+    impl Resource for Stats {}
+
+    #[test]
+    fn cloned_res_shares_ticks() {
+        let value = Score(7);
+        let added = Tick::new(2);
+        let changed = Tick::new(3);
+        let caller = DebugLocation::caller();
+
+        let res = Res {
+            value: &value,
+            ticks: ComponentTicksRef {
+                added: &added,
+                changed: &changed,
+                changed_by: caller.as_ref(),
+                last_run: Tick::new(2),
+                this_run: Tick::new(4),
+            },
+        };
+
+        let copy = res.clone();
+        for res in [&res, &copy] {
+            assert_eq!(res.0, 7);
+            assert_eq!(res.added_tick(), Tick::new(2));
+            assert_eq!(res.changed_tick(), Tick::new(3));
+            assert!(!res.is_added());
+            assert!(res.is_changed());
+        }
+    }
+
+    #[test]
+    fn reborrow_ref_projects_field() {
+        let value = Stats { hits: 9 };
+        let added = Tick::new(1);
+        let changed = Tick::new(5);
+        let caller = DebugLocation::caller();
+
+        let res = Res {
+            value: &value,
+            ticks: ComponentTicksRef {
+                added: &added,
+                changed: &changed,
+                changed_by: caller.as_ref(),
+                last_run: Tick::new(3),
+                this_run: Tick::new(6),
+            },
+        };
+
+        let hits = res.reborrow_ref().map(|stats| &stats.hits);
+        assert_eq!(*hits, 9);
+        assert_eq!(hits.changed_tick(), res.changed_tick());
+        assert_eq!(hits.is_changed(), res.is_changed());
+        assert!(!hits.is_added());
+
+        let whole = res.into_ref();
+        assert_eq!(whole.hits, 9);
+        assert!(whole.is_changed());
+    }
+
+    #[test]
+    fn downcast_mut_matching_type() {
+        let mut value = Score(3);
+        let mut added = Tick::new(1);
+        let mut changed = Tick::new(1);
+        let mut caller = DebugLocation::caller();
+
+        let untyped: MutUntyped = Mut {
+            value: &mut value,
+            ticks: ComponentTicksMut {
+                added: &mut added,
+                changed: &mut changed,
+                changed_by: caller.as_mut(),
+                last_run: Tick::new(1),
+                this_run: Tick::new(2),
+            },
+        }
+        .into();
+
+        let mut score = unsafe { untyped.downcast_mut::<Score>() };
+        assert!(!score.is_changed());
+        score.0 += 1;
+        assert!(score.is_changed());
+        assert_eq!(value.0, 4);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    #[should_panic(expected = "cannot downcast to `u64`")]
+    fn downcast_mut_mismatched_layout() {
+        let mut value = Score(3);
+        let mut added = Tick::new(1);
+        let mut changed = Tick::new(1);
+        let mut caller = DebugLocation::caller();
+
+        let untyped: MutUntyped = Mut {
+            value: &mut value,
+            ticks: ComponentTicksMut {
+                added: &mut added,
+                changed: &mut changed,
+                changed_by: caller.as_mut(),
+                last_run: Tick::new(1),
+                this_run: Tick::new(2),
+            },
+        }
+        .into();
+
+        let _ = unsafe { untyped.downcast_mut::<u64>() };
+    }
+
+    #[test]
+    fn ref_view_does_not_mark_changed() {
+        fn read_hits(stats: Ref<Stats>) -> (u32, bool) {
+            (stats.hits, stats.is_changed())
+        }
+
+        let mut value = Stats { hits: 2 };
+        let mut added = Tick::new(1);
+        let mut changed = Tick::new(1);
+        let mut caller = DebugLocation::caller();
+
+        let mut stats = Mut {
+            value: &mut value,
+            ticks: ComponentTicksMut {
+                added: &mut added,
+                changed: &mut changed,
+                changed_by: caller.as_mut(),
+                last_run: Tick::new(1),
+                this_run: Tick::new(2),
+            },
+        };
+
+        assert_eq!(read_hits(stats.as_ref_mut_ticks()), (2, false));
+        assert!(!stats.is_changed());
+
+        stats.hits += 1;
+        assert_eq!(read_hits(stats.as_ref_mut_ticks()), (3, true));
+    }
+}
@@ -64,6 +64,10 @@ impl Components {
         }
     }
 
+    /// Iterates over the [`ComponentInfo`] of every registered component and resource,
+    /// in [`ComponentId`] order.
+    ///
+    /// Queued registrations that have not been applied yet are not included.
     pub fn iter_registered(&self) -> impl Iterator<Item = &ComponentInfo> + '_ {
         self.infos.iter().filter_map(Option::as_ref)
     }
@@ -206,6 +210,33 @@ impl Components {
         }
     }
 
+    /// Returns all components required by `id`, directly or transitively.
+    ///
+    /// Each component appears once, after all components it requires.
+    /// Returns an empty list if `id` is not registered.
+    pub fn transitive_requirements(&self, id: ComponentId) -> Vec<ComponentId> {
+        fn visit(
+            components: &Components,
+            id: ComponentId,
+            visited: &mut SparseIndexSet<ComponentId>,
+        ) {
+            let Some(required) = components.get_required_components(id) else {
+                return;
+            };
+            for required_id in required.iter() {
+                // Cycles are rejected at registration, so this terminates.
+                if !visited.contains(&required_id) {
+                    visit(components, required_id, visited);
+                    visited.insert(required_id);
+                }
+            }
+        }
+
+        let mut visited = SparseIndexSet::new();
+        visit(self, id, &mut visited);
+        visited.into_iter().collect()
+    }
+
     pub fn get_required_by(&self, id: ComponentId) -> Option<&SparseIndexSet<ComponentId>> {
         self.infos
             .get(id.index())
@@ -283,6 +314,11 @@ impl Components {
         self.get_resource_id(TypeId::of::<T>())
     }
 
+    /// Returns `true` if `id` is registered in these components.
+    ///
+    /// A [`ComponentId`] is only meaningful for the [`World`](crate::world::World)
+    /// that created it, so this also catches ids taken from another world when
+    /// they are out of range here.
     #[inline]
     pub fn is_id_registered(&self, id: ComponentId) -> bool {
         self.infos.get(id.index()).is_some_and(Option::is_some)
@@ -529,3 +565,135 @@ impl Components {
         Ok(())
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Components;
+    use crate::component::{
+        Component, ComponentId, ComponentIdGenerator, ComponentsRegistrator, Mutable,
+        RequiredComponentsRegistrator,
+    };
+    use crate::resource::Resource;
+    use crate::storage::StorageType;
+
+    struct Position;
+    impl Component for Position {
+        const STORAGE_TYPE: StorageType = StorageType::Table;
+        type Mutability = Mutable;
+    }
+
+    struct Marker;
+    impl Component for Marker {
+        const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+        type Mutability = Mutable;
+    }
+
+    struct Time;
+    impl Resource for Time {}
+
+    struct Queued;
+    impl Resource for Queued {}
+
+    #[test]
+    fn iter_registered_skips_queued() {
+        let mut components = Components::empty();
+        let mut generator = ComponentIdGenerator::new();
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut components, &mut generator) };
+        let position = registrator.register_component::<Position>();
+        let marker = registrator.register_component::<Marker>();
+        let time = registrator.register_resource::<Time>();
+        registrator.as_queued().queue_register_resource::<Queued>();
+
+        let infos: Vec<_> = components
+            .iter_registered()
+            .map(|info| (info.id(), info.storage_type(), info.is_resource()))
+            .collect();
+
+        assert_eq!(
+            infos,
+            [
+                (position, StorageType::Table, false),
+                (marker, StorageType::SparseSet, false),
+                (time, StorageType::SparseSet, true),
+            ],
+        );
+        assert_eq!(components.num_queued(), 1);
+    }
+
+    struct A;
+    impl Component for A {
+        const STORAGE_TYPE: StorageType = StorageType::Table;
+        type Mutability = Mutable;
+
+        fn register_required_components(
+            _id: ComponentId,
+            registrator: &mut RequiredComponentsRegistrator,
+        ) {
+            registrator.register_required(|| B);
+        }
+    }
+
+    struct B;
+    impl Component for B {
+        const STORAGE_TYPE: StorageType = StorageType::Table;
+        type Mutability = Mutable;
+
+        fn register_required_components(
+            _id: ComponentId,
+            registrator: &mut RequiredComponentsRegistrator,
+        ) {
+            registrator.register_required(|| C);
+        }
+    }
+
+    struct C;
+    impl Component for C {
+        const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+        type Mutability = Mutable;
+    }
+
+    #[derive(Component)]
+    struct Dense;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Tag;
+
+    #[test]
+    fn transitive_requirements_closure() {
+        let mut components = Components::empty();
+        let mut generator = ComponentIdGenerator::new();
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut components, &mut generator) };
+        let a = registrator.register_component::<A>();
+        let b = registrator.register_component::<B>();
+        let c = registrator.register_component::<C>();
+
+        let info = components.get_info(a).unwrap();
+        assert_eq!(info.required_components().iter().collect::<Vec<_>>(), [b]);
+
+        assert_eq!(components.transitive_requirements(a), [c, b]);
+        assert_eq!(components.transitive_requirements(b), [c]);
+        assert!(components.transitive_requirements(c).is_empty());
+    }
+
+    #[test]
+    fn derive_sets_storage_type() {
+        let mut components = Components::empty();
+        let mut generator = ComponentIdGenerator::new();
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut components, &mut generator) };
+        let dense = registrator.register_component::<Dense>();
+        let tag = registrator.register_component::<Tag>();
+
+        let storage_type = |id| components.get_info(id).unwrap().storage_type();
+        assert_eq!(storage_type(dense), StorageType::Table);
+        assert_eq!(storage_type(tag), StorageType::SparseSet);
+    }
+}
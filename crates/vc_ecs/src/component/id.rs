@@ -117,7 +117,7 @@ impl ComponentIdGenerator {
 
     #[inline]
     pub fn peek(&self) -> ComponentId {
-        let next = self.next.fetch_add(1, Ordering::Relaxed);
+        let next = self.next.load(Ordering::Relaxed);
         unsafe { Self::force_cast(next) }
     }
 
@@ -20,6 +20,7 @@ use crate::utils::DebugName;
 pub struct ComponentDescriptor {
     debug_name: DebugName,
     storage_type: StorageType,
+    is_resource: bool,
     is_send_and_sync: bool,
     type_id: Option<TypeId>,
     layout: Layout,
@@ -36,6 +37,12 @@ impl ComponentDescriptor {
         self.storage_type
     }
 
+    /// Returns `true` if this describes a resource (`Send` or not) rather than a component.
+    #[inline(always)]
+    pub fn is_resource(&self) -> bool {
+        self.is_resource
+    }
+
     /// Returns the [`TypeId`] of the underlying component type.
     /// Returns `None` if the component does not correspond to a Rust type.
     #[inline(always)]
@@ -86,6 +93,11 @@ impl ComponentInfo {
         self.descriptor.storage_type
     }
 
+    #[inline(always)]
+    pub const fn is_resource(&self) -> bool {
+        self.descriptor.is_resource
+    }
+
     #[inline(always)]
     pub const fn layout(&self) -> Layout {
         self.descriptor.layout
@@ -126,6 +138,7 @@ impl ComponentInfo {
         &self.hooks
     }
 
+    /// Returns the components required by this component.
     #[inline(always)]
     pub const fn required_components(&self) -> &RequiredComponents {
         &self.required_components
@@ -206,6 +219,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop_fn: get_drop_fn::<T>(),
             mutable: T::Mutability::MUTABLE,
+            is_resource: false,
             is_send_and_sync: true,
             storage_type: T::STORAGE_TYPE,
             debug_name: DebugName::type_name::<T>(),
@@ -221,6 +235,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop_fn: get_drop_fn::<T>(),
             mutable: true,
+            is_resource: true,
             is_send_and_sync: true,
             // This field has no effect for `Resource` types,
             // as they are always stored in `Resources` rather
@@ -239,6 +254,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop_fn: get_drop_fn::<T>(),
             mutable: true,
+            is_resource: true,
             is_send_and_sync: false,
             storage_type: StorageType::Table,
             debug_name: DebugName::type_name::<T>(),
@@ -260,6 +276,7 @@ impl ComponentDescriptor {
         Self {
             debug_name,
             storage_type,
+            is_resource: false,
             is_send_and_sync: true,
             type_id: None,
             layout,
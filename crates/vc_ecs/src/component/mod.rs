@@ -24,6 +24,8 @@ pub(crate) use tick::{ComponentTicksMut, ComponentTicksRef};
 // -----------------------------------------------------------------------------
 // Exports
 
+pub use vc_ecs_derive::Component;
+
 pub use id::{ComponentId, ComponentIdGenerator};
 
 pub use borrow::{Mut, MutUntyped, Ref};
@@ -205,6 +205,13 @@ impl RequiredComponents {
         }
     }
 
+    /// Iterates the ids of the directly required components.
+    ///
+    /// See [`iter_ids`](Self::iter_ids) to include recursive required components.
+    pub fn iter(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.direct.keys().copied()
+    }
+
     /// Iterates the ids of all required components. This includes recursive required components.
     pub fn iter_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
         self.all.keys().copied()
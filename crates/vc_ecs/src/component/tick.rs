@@ -11,6 +11,10 @@ use crate::utils::DebugLocation;
 // -----------------------------------------------------------------------------
 // ComponentTicks
 
+/// The ticks at which a component value was added and last changed.
+///
+/// This is the owned form of the ticks stored next to each value,
+/// usable for change detection over custom storage.
 #[derive(Copy, Clone, Debug)]
 pub struct ComponentTicks {
     pub added: Tick,
@@ -18,6 +22,7 @@ pub struct ComponentTicks {
 }
 
 impl ComponentTicks {
+    /// Creates ticks for a value added (and thus changed) at `tick`.
     #[inline(always)]
     pub const fn new(tick: Tick) -> Self {
         Self {
@@ -26,16 +31,27 @@ impl ComponentTicks {
         }
     }
 
+    /// Marks the value as changed at `change_tick`.
     #[inline(always)]
     pub const fn set_changed(&mut self, change_tick: Tick) {
         self.changed = change_tick;
     }
 
+    /// Returns `true` if the value was added after `last_run`, as seen at `this_run`.
+    ///
+    /// This is the same check [`DetectChanges::is_added`] performs.
+    ///
+    /// [`DetectChanges::is_added`]: crate::change_detection::DetectChanges::is_added
     #[inline]
     pub const fn is_added(&self, last_run: Tick, this_run: Tick) -> bool {
         self.added.is_newer_than(last_run, this_run)
     }
 
+    /// Returns `true` if the value was changed after `last_run`, as seen at `this_run`.
+    ///
+    /// This is the same check [`DetectChanges::is_changed`] performs.
+    ///
+    /// [`DetectChanges::is_changed`]: crate::change_detection::DetectChanges::is_changed
     #[inline]
     pub const fn is_changed(&self, last_run: Tick, this_run: Tick) -> bool {
         self.changed.is_newer_than(last_run, this_run)
@@ -127,3 +143,30 @@ impl<'w> From<ComponentTicksMut<'w>> for ComponentTicksRef<'w> {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentTicks;
+    use crate::tick::Tick;
+
+    #[test]
+    fn run_windows() {
+        let mut ticks = ComponentTicks::new(Tick::new(5));
+        assert_eq!(ticks.added, ticks.changed);
+
+        // Systems that last ran before the value was added see it as added.
+        assert!(ticks.is_added(Tick::new(4), Tick::new(6)));
+        assert!(ticks.is_added(Tick::new(0), Tick::new(5)));
+        // Systems that already ran at or after that tick do not.
+        assert!(!ticks.is_added(Tick::new(5), Tick::new(6)));
+        assert!(!ticks.is_added(Tick::new(7), Tick::new(8)));
+
+        ticks.set_changed(Tick::new(9));
+        assert!(!ticks.is_added(Tick::new(6), Tick::new(10)));
+        assert!(ticks.is_changed(Tick::new(6), Tick::new(10)));
+        assert!(!ticks.is_changed(Tick::new(9), Tick::new(10)));
+    }
+}
@@ -6,14 +6,40 @@ use vc_os::sync::atomic::{AtomicU32, AtomicUsize};
 
 use super::{Entity, EntityId};
 
+// -----------------------------------------------------------------------------
+// ReusePolicy
+
+/// The order in which an [`EntityAllocator`] hands out freed entity ids.
+///
+/// Both policies bump the generation of a reused id in the same way,
+/// they only differ in which freed id is reused first.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReusePolicy {
+    /// Reuse the most recently freed id first.
+    #[default]
+    Lifo,
+    /// Reuse the least recently freed id first.
+    ///
+    /// This keeps recycled ids low and dense when the oldest entities are
+    /// despawned first.
+    Fifo,
+}
+
 // -----------------------------------------------------------------------------
 // EntityAllocator
 
 #[derive(Debug)]
 pub struct EntityAllocator {
+    /// Freed entities, in the order they were freed.
     free: Vec<Entity>,
+    /// With [`ReusePolicy::Lifo`], `free[..free_len]` are waiting to be reused,
+    /// the next one is at the end.
     free_len: AtomicUsize,
+    /// With [`ReusePolicy::Fifo`], `free[free_head..]` are waiting to be reused,
+    /// the next one is at the front.
+    free_head: AtomicUsize,
     next_index: AtomicU32,
+    policy: ReusePolicy,
 }
 
 impl Default for EntityAllocator {
@@ -25,11 +51,56 @@ impl Default for EntityAllocator {
 
 impl EntityAllocator {
     pub const fn new() -> Self {
+        Self::with_policy(ReusePolicy::Lifo)
+    }
+
+    /// Creates an allocator that reuses freed ids according to `policy`.
+    pub const fn with_policy(policy: ReusePolicy) -> Self {
         Self {
             free: Vec::new(),
             free_len: AtomicUsize::new(0),
+            free_head: AtomicUsize::new(0),
             // SAFETY: start from `1`, instead of `0`.
             next_index: AtomicU32::new(1),
+            policy,
+        }
+    }
+
+    /// Returns the [`ReusePolicy`] of this allocator.
+    #[inline]
+    pub const fn policy(&self) -> ReusePolicy {
+        self.policy
+    }
+
+    /// Returns the number of entity ids ever allocated, i.e. the high-water mark.
+    ///
+    /// Reused ids are not counted again. Together with [`free_count`](Self::free_count)
+    /// and [`Entities::count_spawned`](super::Entities::count_spawned), this helps
+    /// diagnose fragmentation and id churn.
+    #[inline]
+    pub fn total_count(&self) -> usize {
+        // `next_index` starts from `1`.
+        self.next_index.load(Ordering::Relaxed) as usize - 1
+    }
+
+    /// Returns the number of freed entity ids waiting to be reused.
+    #[inline]
+    pub fn free_count(&self) -> usize {
+        match self.policy {
+            ReusePolicy::Lifo => {
+                // `alloc` may decrement `free_len` past zero, which wraps it around.
+                let free_len = self.free_len.load(Ordering::Relaxed);
+                if free_len <= self.free.len() {
+                    free_len
+                } else {
+                    0
+                }
+            }
+            ReusePolicy::Fifo => {
+                // `alloc` may advance `free_head` past the end.
+                let free_head = self.free_head.load(Ordering::Relaxed);
+                self.free.len().saturating_sub(free_head)
+            }
         }
     }
 
@@ -37,26 +108,47 @@ impl EntityAllocator {
     pub fn restart(&mut self) {
         self.free.clear();
         *self.free_len.get_mut() = 0;
+        *self.free_head.get_mut() = 0;
         // SAFETY: start from `1`, instead of `0`.
         *self.next_index.get_mut() = 1;
     }
 
     pub fn free(&mut self, freed: Entity) {
-        let expected_len = *self.free_len.get_mut();
-        if expected_len > self.free.len() {
-            self.free.clear();
-        } else {
-            self.free.truncate(expected_len);
+        match self.policy {
+            ReusePolicy::Lifo => {
+                let expected_len = *self.free_len.get_mut();
+                if expected_len > self.free.len() {
+                    self.free.clear();
+                } else {
+                    self.free.truncate(expected_len);
+                }
+                self.free.push(freed);
+                *self.free_len.get_mut() = self.free.len();
+            }
+            ReusePolicy::Fifo => {
+                let free_head = self.free_head.get_mut();
+                if *free_head >= self.free.len() {
+                    self.free.clear();
+                    *free_head = 0;
+                } else if *free_head * 2 >= self.free.len() {
+                    // Drop the reused prefix once it makes up half of the list,
+                    // so each freed id is moved at most once on average.
+                    self.free.drain(..*free_head);
+                    *free_head = 0;
+                }
+                self.free.push(freed);
+            }
         }
-        self.free.push(freed);
-        *self.free_len.get_mut() = self.free.len();
     }
 
     pub fn alloc(&self) -> Entity {
-        let index = self
-            .free_len
-            .fetch_sub(1, Ordering::Relaxed)
-            .wrapping_sub(1);
+        let index = match self.policy {
+            ReusePolicy::Lifo => self
+                .free_len
+                .fetch_sub(1, Ordering::Relaxed)
+                .wrapping_sub(1),
+            ReusePolicy::Fifo => self.free_head.fetch_add(1, Ordering::Relaxed),
+        };
 
         self.free.get(index).copied().unwrap_or_else(|| {
             let index = self.next_index.fetch_add(1, Ordering::Relaxed);
@@ -73,18 +165,24 @@ impl EntityAllocator {
         // Ensure that count <= u32::MAX.
         let count = count as usize;
 
-        let current_len = self.free_len.fetch_sub(count, Ordering::Relaxed);
-
-        let current_len = if current_len <= self.free.len() {
-            current_len
-        } else {
-            0
+        let reuse = match self.policy {
+            ReusePolicy::Lifo => {
+                let current_len = self.free_len.fetch_sub(count, Ordering::Relaxed);
+                let current_len = if current_len <= self.free.len() {
+                    current_len
+                } else {
+                    0
+                };
+                current_len.saturating_sub(count)..current_len
+            }
+            ReusePolicy::Fifo => {
+                let free_head = self.free_head.fetch_add(count, Ordering::Relaxed);
+                let start = free_head.min(self.free.len());
+                start..free_head.saturating_add(count).min(self.free.len())
+            }
         };
 
-        let start = current_len.saturating_sub(count);
-        let reuse = start..current_len;
-
-        let still_need = (count + start - current_len) as u32;
+        let still_need = (count - reuse.len()) as u32;
         let new = if still_need == 0 {
             0..0
         } else {
@@ -132,3 +230,112 @@ impl<'a> Iterator for AllocatedEntities<'a> {
 impl<'a> ExactSizeIterator for AllocatedEntities<'a> {}
 
 impl<'a> core::iter::FusedIterator for AllocatedEntities<'a> {}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use nonmax::NonMaxU32;
+
+    use super::{EntityAllocator, ReusePolicy};
+    use crate::archetype::{ArchetypeId, ArchetypeRow};
+    use crate::entity::{Entities, Entity, EntityLocation};
+    use crate::storage::{TableId, TableRow};
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+
+    fn reuse_order(policy: ReusePolicy) -> Vec<Entity> {
+        let mut allocator = EntityAllocator::with_policy(policy);
+        let mut entities = Entities::empty();
+
+        let spawned: Vec<Entity> = (0..8).map(|_| allocator.alloc()).collect();
+        for entity in &spawned[4..7] {
+            assert!((5..=7).contains(&entity.index_u32()));
+            let freed = unsafe { entities.make_free(entity.id(), 1) };
+            assert_ne!(freed, *entity);
+            allocator.free(freed);
+        }
+
+        (0..3).map(|_| allocator.alloc()).collect()
+    }
+
+    #[test]
+    fn fifo_reuses_oldest_first() {
+        let reused = reuse_order(ReusePolicy::Fifo);
+        let indices: Vec<u32> = reused.iter().map(|e| e.index_u32()).collect();
+        assert_eq!(indices, [5, 6, 7]);
+        assert!(
+            reused
+                .iter()
+                .all(|e| e.generation() != Entity::from_id(e.id()).generation())
+        );
+    }
+
+    #[test]
+    fn fifo_frees_large_batch() {
+        const COUNT: u32 = 100_000;
+
+        let mut allocator = EntityAllocator::with_policy(ReusePolicy::Fifo);
+        let spawned: Vec<Entity> = allocator.alloc_many(COUNT).collect();
+        for entity in &spawned {
+            allocator.free(*entity);
+        }
+        assert_eq!(allocator.free_count(), COUNT as usize);
+
+        // Interleave reuse and frees, the order must stay first-in first-out.
+        let reused: Vec<Entity> = (0..COUNT / 2).map(|_| allocator.alloc()).collect();
+        assert_eq!(reused, spawned[..COUNT as usize / 2]);
+        for entity in &reused {
+            allocator.free(*entity);
+        }
+        let reused: Vec<Entity> = allocator.alloc_many(COUNT).collect();
+        assert_eq!(reused[..COUNT as usize / 2], spawned[COUNT as usize / 2..]);
+        assert_eq!(reused[COUNT as usize / 2..], spawned[..COUNT as usize / 2]);
+        assert_eq!(allocator.free_count(), 0);
+        assert_eq!(allocator.total_count(), COUNT as usize);
+    }
+
+    #[test]
+    fn lifo_reuses_newest_first() {
+        let reused = reuse_order(ReusePolicy::Lifo);
+        let indices: Vec<u32> = reused.iter().map(|e| e.index_u32()).collect();
+        assert_eq!(indices, [7, 6, 5]);
+    }
+
+    #[test]
+    fn allocation_diagnostics() {
+        let mut allocator = EntityAllocator::new();
+        let mut entities = Entities::empty();
+        let location = EntityLocation {
+            archetype_id: ArchetypeId::EMPTY,
+            archetype_row: ArchetypeRow::new(NonMaxU32::ZERO),
+            table_id: TableId::EMPTY,
+            table_row: TableRow::new(NonMaxU32::ZERO),
+        };
+
+        let spawned: Vec<Entity> = allocator.alloc_many(10).collect();
+        entities.flush(
+            spawned.iter().copied(),
+            DebugLocation::caller(),
+            Tick::new(1),
+            |_| location,
+        );
+        for entity in &spawned[..4] {
+            entities.set_location(entity.id(), None);
+            let freed = unsafe { entities.make_free(entity.id(), 1) };
+            allocator.free(freed);
+        }
+
+        assert_eq!(allocator.total_count(), 10);
+        assert_eq!(allocator.free_count(), 4);
+        assert_eq!(entities.count_spawned(), 6);
+
+        // Reusing ids does not raise the high-water mark.
+        let _ = allocator.alloc_many(5).count();
+        assert_eq!(allocator.total_count(), 11);
+        assert_eq!(allocator.free_count(), 0);
+    }
+}
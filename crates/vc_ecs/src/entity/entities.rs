@@ -54,6 +54,15 @@ impl Entities {
         Self { meta: Vec::new() }
     }
 
+    /// Creates the location table with room for `capacity` entity ids,
+    /// avoiding reallocations while spawning up to that many entities.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            meta: Vec::with_capacity(capacity),
+        }
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.meta.clear();
@@ -128,6 +137,17 @@ impl Entities {
             .is_some_and(|meta| entity.generation() == meta.generation)
     }
 
+    /// Returns `true` if `entity` is spawned and is the current handle for its index.
+    ///
+    /// Unlike [`Entities::contains_spawned_by_id`], which only checks whether the
+    /// index is occupied, this also compares the generation: a stale handle whose
+    /// index was recycled returns `false`. Reserved entities that have not been
+    /// [flushed](Entities::flush) yet are not alive either.
+    #[inline]
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        self.contains_spawned(entity)
+    }
+
     #[inline]
     pub fn contains_spawned_by_id(&self, id: EntityId) -> bool {
         self.meta
@@ -187,6 +207,48 @@ impl Entities {
         unsafe { self.set_location_unchecked(id, location) }
     }
 
+    /// Moves the spawned `entity` to `location`, returning its previous location.
+    ///
+    /// Storage code calls this after moving an entity between archetypes or tables,
+    /// so that [`Entities::get_location`] keeps pointing at the entity's data.
+    /// Entities whose row was swapped into the vacated slot must be updated too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` is not spawned, e.g. it is a stale handle.
+    #[track_caller]
+    pub fn update_location(&mut self, entity: Entity, location: EntityLocation) -> EntityLocation {
+        if let Err(err) = self.get_location_spawned(entity) {
+            panic!("can not update the location of {entity}: {err}");
+        }
+        // SAFETY: spawned entities always have a valid meta slot.
+        unsafe { self.set_location_unchecked(entity.id(), Some(location)) }.unwrap()
+    }
+
+    /// Assigns locations to `reserved` entities in bulk, typically the result of
+    /// [`EntityAllocator::alloc_many`](super::EntityAllocator::alloc_many).
+    ///
+    /// `init` is called once per entity and returns the location it was placed at.
+    /// Each entity records `caller` and `tick` as its spawn metadata, like
+    /// entities spawned one at a time. The reserved entities must not be spawned yet.
+    pub fn flush(
+        &mut self,
+        reserved: impl IntoIterator<Item = Entity>,
+        caller: DebugLocation,
+        tick: Tick,
+        mut init: impl FnMut(Entity) -> EntityLocation,
+    ) {
+        for entity in reserved {
+            debug_assert!(
+                self.check_spawnable(entity).is_ok(),
+                "flushed entity {entity} is not a reserved entity",
+            );
+            let location = init(entity);
+            self.set_location(entity.id(), Some(location));
+            self.set_spawned_or_despawned(entity.id(), caller, tick);
+        }
+    }
+
     /// 更新（增加）一个 Entity 的 generation，并返回其结果。
     ///
     /// 更新 generation，表示旧的 Entity 已经失效，调用前应当预先清理资源。
@@ -267,3 +329,124 @@ impl Entities {
             .count()
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use nonmax::NonMaxU32;
+
+    use super::Entities;
+    use crate::archetype::{ArchetypeId, ArchetypeRow};
+    use crate::entity::{Entity, EntityAllocator, EntityLocation};
+    use crate::storage::{TableId, TableRow};
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+
+    fn location(row: u32) -> EntityLocation {
+        EntityLocation {
+            archetype_id: ArchetypeId::EMPTY,
+            archetype_row: ArchetypeRow::new(NonMaxU32::new(row).unwrap()),
+            table_id: TableId::EMPTY,
+            table_row: TableRow::new(NonMaxU32::new(row).unwrap()),
+        }
+    }
+
+    #[test]
+    fn alive_after_flush() {
+        let (caller, tick) = (DebugLocation::caller(), Tick::new(1));
+        let allocator = EntityAllocator::new();
+        let mut entities = Entities::empty();
+
+        let reserved: alloc::vec::Vec<Entity> = allocator.alloc_many(3).collect();
+        for &entity in &reserved {
+            assert!(!entities.is_alive(entity));
+            assert!(!entities.contains_spawned_by_id(entity.id()));
+        }
+
+        let mut row = 0;
+        entities.flush(reserved.iter().copied(), caller, tick, |_| {
+            row += 1;
+            location(row - 1)
+        });
+
+        for (index, &entity) in reserved.iter().enumerate() {
+            assert!(entities.is_alive(entity));
+            assert_eq!(entities.get_spawn_or_despawn_tick(entity), Some(tick));
+            assert_eq!(
+                entities.get_spawned_or_despawned_by(entity),
+                caller.map(Some)
+            );
+            assert_eq!(
+                entities.get_location_spawned(entity).unwrap(),
+                location(index as u32),
+            );
+        }
+    }
+
+    #[test]
+    fn update_location_moves_spawned_entity() {
+        let (caller, tick) = (DebugLocation::caller(), Tick::new(1));
+        let allocator = EntityAllocator::new();
+        let mut entities = Entities::empty();
+
+        let entity = allocator.alloc();
+        entities.flush([entity], caller, tick, |_| location(0));
+
+        let moved = EntityLocation {
+            archetype_id: ArchetypeId::new(1),
+            ..location(3)
+        };
+        assert_eq!(entities.update_location(entity, moved), location(0));
+        assert_eq!(entities.get_location_spawned(entity).unwrap(), moved);
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_location_rejects_unspawned_entity() {
+        let allocator = EntityAllocator::new();
+        let mut entities = Entities::empty();
+        entities.update_location(allocator.alloc(), location(0));
+    }
+
+    #[test]
+    fn stale_handle_is_not_alive() {
+        let (caller, tick) = (DebugLocation::caller(), Tick::new(1));
+        let mut allocator = EntityAllocator::new();
+        let mut entities = Entities::empty();
+
+        let old = allocator.alloc();
+        entities.flush([old], caller, tick, |_| location(0));
+        assert!(entities.is_alive(old));
+
+        entities.set_location(old.id(), None);
+        let freed = unsafe { entities.make_free(old.id(), 1) };
+        allocator.free(freed);
+
+        let recycled = allocator.alloc();
+        assert_eq!(recycled.id(), old.id());
+        entities.flush([recycled], caller, tick, |_| location(0));
+
+        assert!(entities.is_alive(recycled));
+        assert!(!entities.is_alive(old));
+        // The index itself is still occupied.
+        assert!(entities.contains_spawned_by_id(old.id()));
+    }
+
+    #[test]
+    fn reserved_unflushed_is_not_alive() {
+        let allocator = EntityAllocator::new();
+        let mut entities = Entities::empty();
+
+        let reserved: alloc::vec::Vec<Entity> = allocator.alloc_many(2).collect();
+        let (caller, tick) = (DebugLocation::caller(), Tick::new(1));
+        entities.flush([reserved[0]], caller, tick, |_| location(0));
+
+        let pending = reserved[1];
+        assert!(entities.is_alive(reserved[0]));
+        assert!(!entities.is_alive(pending));
+        assert!(!entities.contains_spawned_by_id(pending.id()));
+        assert!(entities.check_spawnable(pending).is_ok());
+    }
+}
@@ -104,6 +104,11 @@ impl PartialEq for Entity {
 impl Eq for Entity {}
 
 impl fmt::Display for Entity {
+    /// Formats the entity as `{index}v{generation}`, e.g. `42v3`,
+    /// or as `PLACEHOLDER` for [`Entity::PLACEHOLDER`].
+    ///
+    /// The format is stable, so it can be searched for in logs.
+    /// `Debug` uses the same format.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self == &Self::PLACEHOLDER {
             f.pad("PLACEHOLDER")
@@ -172,9 +177,16 @@ impl<'de> Deserialize<'de> for Entity {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Tests
+
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+    use core::num::NonZeroU32;
+
     use super::Entity;
+    use crate::entity::{EntityGeneration, EntityId};
 
     #[test]
     fn entity_is_u64() {
@@ -187,4 +199,15 @@ mod tests {
             123456789012_u64
         );
     }
+
+    #[test]
+    fn display_index_and_generation() {
+        let id = EntityId::new(NonZeroU32::new(42).unwrap());
+        let entity = Entity::new(id, EntityGeneration::FIRST.after(3));
+        assert_eq!(format!("{entity}"), "42v3");
+        assert_eq!(format!("{entity:?}"), "42v3");
+        assert_eq!(format!("{entity:>6}"), "  42v3");
+
+        assert_eq!(format!("{}", Entity::PLACEHOLDER), "PLACEHOLDER");
+    }
 }
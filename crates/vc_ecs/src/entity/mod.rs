@@ -17,7 +17,7 @@ pub mod error;
 
 pub use utils::*;
 
-pub use allocator::EntityAllocator;
+pub use allocator::{EntityAllocator, ReusePolicy};
 pub use clone::ComponentCloneCtx;
 pub use entities::Entities;
 pub use entity::Entity;
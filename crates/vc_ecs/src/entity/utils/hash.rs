@@ -56,3 +56,68 @@ unsafe impl<I: Iterator<Item = Entity>> EntitySetIterator
     for set::Splice<'_, I, Entity, SparseHashState>
 {
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::num::NonZeroU32;
+
+    use super::{EntityHashMap, EntityHashSet, EntityIndexMap, EntityIndexSet};
+    use crate::entity::{Entity, EntityId};
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_id(EntityId::new(NonZeroU32::new(index).unwrap()))
+    }
+
+    #[test]
+    fn index_map_sorts_by_entity() {
+        let mut map = EntityIndexMap::default();
+        for index in [5, 1, 4, 2] {
+            map.insert(entity(index), index);
+        }
+        assert_eq!(map.get_index(0), Some((&entity(5), &5)));
+
+        map.sort_keys();
+        let values: Vec<u32> = map.values().copied().collect();
+        assert_eq!(values, [1, 2, 4, 5]);
+
+        map.swap_indices(0, 3);
+        map.move_index(0, 1);
+        let keys: Vec<Entity> = map.keys().copied().collect();
+        assert_eq!(keys, [entity(2), entity(5), entity(4), entity(1)]);
+        assert!(map.contains_key(&entity(4)));
+    }
+
+    #[test]
+    fn index_set_sorts_by_entity() {
+        let mut set: EntityIndexSet = [3, 1, 2].into_iter().map(entity).collect();
+        set.sort();
+        let entities: Vec<Entity> = set.iter().copied().collect();
+        assert_eq!(entities, [entity(1), entity(2), entity(3)]);
+    }
+
+    #[test]
+    fn reserve_avoids_reallocation() {
+        let mut map = EntityHashMap::default();
+        map.reserve(1000);
+        let capacity = map.capacity();
+        assert!(capacity >= 1000);
+        for index in 1..=1000 {
+            map.insert(entity(index), index);
+            assert_eq!(map.capacity(), capacity);
+        }
+
+        let mut set = EntityHashSet::with_capacity(1000);
+        let capacity = set.capacity();
+        set.extend((1..=1000).map(entity));
+        assert_eq!(set.capacity(), capacity);
+
+        set.retain(|entity| entity.index() <= 10);
+        set.shrink_to_fit();
+        assert!(set.capacity() < capacity);
+        assert_eq!(set.len(), 10);
+    }
+}
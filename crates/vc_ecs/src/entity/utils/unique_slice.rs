@@ -739,6 +739,26 @@ impl<T: EntityEquivalent> UniqueEntityEquivalentSlice<T> {
         self.0.sort_by_cached_key(f);
     }
 
+    /// Sorts the slice by [`Entity`], enabling [`binary_search_entity`](Self::binary_search_entity).
+    ///
+    /// The entities are unique, so there are no equal elements and a stable
+    /// sort is not needed. Sorting can never introduce duplicates either.
+    pub fn sort_by_entity(&mut self) {
+        self.0.sort_unstable_by_key(T::entity);
+    }
+
+    /// Binary searches this slice for `entity`.
+    ///
+    /// Returns `Ok` with the index of the matching element, or `Err` with the
+    /// index where it could be inserted while keeping the order.
+    ///
+    /// The slice must be sorted by [`Entity`], e.g. by [`sort_by_entity`](Self::sort_by_entity),
+    /// otherwise the result is unspecified. Unlike [`[T]::binary_search`](slice::binary_search),
+    /// the match is always unique since the entities are.
+    pub fn binary_search_entity(&self, entity: Entity) -> Result<usize, usize> {
+        self.0.binary_search_by_key(&entity, T::entity)
+    }
+
     /// Copies self into a new `UniqueEntityEquivalentVec`.
     pub fn to_vec(&self) -> UniqueEntityEquivalentVec<T>
     where
@@ -8,8 +8,8 @@ use core::ops::{Range, RangeBounds, RangeFrom, RangeFull};
 use core::ops::{RangeInclusive, RangeTo, RangeToInclusive};
 
 use super::unique_iter::UniqueEntityIter;
-use super::unique_slice::UniqueEntityEquivalentSlice;
-use super::{EntityEquivalent, EntitySet, FromEntitySet};
+use super::unique_slice::{UniqueEntityEquivalentSlice, UniqueEntitySlice};
+use super::{EntityEquivalent, EntityHashSet, EntitySet, FromEntitySet};
 use crate::entity::Entity;
 
 // -----------------------------------------------------------------------------
@@ -414,6 +414,78 @@ impl<T: EntityEquivalent> UniqueEntityEquivalentVec<T> {
     }
 }
 
+// -----------------------------------------------------------------------------
+// UniqueEntityVec Implementation
+
+impl UniqueEntityVec {
+    /// Appends the entities of `other` that are not already in `self`,
+    /// keeping their relative order.
+    ///
+    /// Membership is checked with a transient [`EntityHashSet`] of `self`,
+    /// so this is O(n + m) on average.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vc_ecs::entity::{Entity, EntityId, UniqueEntityVec};
+    /// # use core::num::NonZeroU32;
+    /// # let e = |i| Entity::from_id(EntityId::new(NonZeroU32::new(i).unwrap()));
+    /// let mut a = UniqueEntityVec::from_iter([e(1), e(2)]);
+    /// let b = UniqueEntityVec::from_iter([e(3), e(2)]);
+    ///
+    /// a.extend_dedup(&b);
+    /// assert_eq!(a.as_inner(), &[e(1), e(2), e(3)]);
+    /// ```
+    ///
+    /// [`EntityHashSet`]: crate::entity::EntityHashSet
+    pub fn extend_dedup(&mut self, other: &UniqueEntitySlice) {
+        let mut present = EntityHashSet::with_capacity(self.len());
+        present.extend(self.0.iter().copied());
+
+        self.0.reserve(other.len());
+        self.0
+            .extend(other.iter().copied().filter(|e| !present.contains(e)));
+    }
+
+    /// Merges the entities of `other` into `self`, skipping those already present.
+    ///
+    /// Both `self` and `other` must be sorted, the result is sorted as well.
+    /// This is O(n + m) and does not allocate a hash set,
+    /// see [`extend_dedup`](Self::extend_dedup) for unsorted collections.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` or `other` is not sorted.
+    #[track_caller]
+    pub fn merge_sorted(&mut self, other: &UniqueEntitySlice) {
+        assert!(
+            self.0.is_sorted() && other.is_sorted(),
+            "`merge_sorted` requires both collections to be sorted",
+        );
+
+        let lhs = core::mem::take(&mut self.0);
+        let mut merged = Vec::with_capacity(lhs.len() + other.len());
+        let (mut lhs, mut rhs) = (lhs.into_iter().peekable(), other.iter().copied().peekable());
+
+        loop {
+            let next = match (lhs.peek(), rhs.peek()) {
+                (Some(a), Some(b)) if a < b => lhs.next(),
+                (Some(a), Some(b)) if a > b => rhs.next(),
+                (Some(_), Some(_)) => {
+                    rhs.next();
+                    lhs.next()
+                }
+                (Some(_), None) => lhs.next(),
+                (None, Some(_)) => rhs.next(),
+                (None, None) => break,
+            };
+            merged.extend(next);
+        }
+
+        self.0 = merged;
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Default Extend
 
@@ -1106,3 +1178,71 @@ impl<'a, T: EntityEquivalent> UniqueEntityIter<vec::Drain<'a, T>> {
 
 /// A splicing iterator for [`UniqueEntityEquivalentVec`].
 pub type Splice<'a, I> = UniqueEntityIter<vec::Splice<'a, I>>;
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use super::UniqueEntityVec;
+    use crate::entity::{Entity, EntityId};
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_id(EntityId::new(NonZeroU32::new(index).unwrap()))
+    }
+
+    fn entities(indices: &[u32]) -> UniqueEntityVec {
+        indices.iter().map(|&index| entity(index)).collect()
+    }
+
+    #[test]
+    fn extend_dedup() {
+        let mut disjoint = entities(&[3, 1]);
+        disjoint.extend_dedup(&entities(&[4, 2]));
+        assert_eq!(disjoint, entities(&[3, 1, 4, 2]));
+
+        let mut overlapping = entities(&[3, 1]);
+        overlapping.extend_dedup(&entities(&[1, 3]));
+        assert_eq!(overlapping, entities(&[3, 1]));
+
+        let mut partial = entities(&[3, 1]);
+        partial.extend_dedup(&entities(&[2, 3, 5]));
+        assert_eq!(partial, entities(&[3, 1, 2, 5]));
+    }
+
+    #[test]
+    fn merge_sorted() {
+        let mut disjoint = entities(&[1, 3]);
+        disjoint.merge_sorted(&entities(&[2, 4]));
+        assert_eq!(disjoint, entities(&[1, 2, 3, 4]));
+
+        let mut overlapping = entities(&[1, 3]);
+        overlapping.merge_sorted(&entities(&[1, 3]));
+        assert_eq!(overlapping, entities(&[1, 3]));
+
+        let mut partial = entities(&[1, 3, 6]);
+        partial.merge_sorted(&entities(&[2, 3, 5]));
+        assert_eq!(partial, entities(&[1, 2, 3, 5, 6]));
+    }
+
+    #[test]
+    fn sort_then_binary_search() {
+        let mut vec = entities(&[7, 2, 9, 4]);
+        vec.sort_by_entity();
+        assert_eq!(vec, entities(&[2, 4, 7, 9]));
+
+        assert_eq!(vec.binary_search_entity(entity(7)), Ok(2));
+        assert_eq!(vec.binary_search_entity(entity(2)), Ok(0));
+        assert_eq!(vec.binary_search_entity(entity(5)), Err(2));
+        assert_eq!(vec.binary_search_entity(entity(10)), Err(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires both collections to be sorted")]
+    fn merge_sorted_unsorted_panics() {
+        let mut vec = entities(&[3, 1]);
+        vec.merge_sorted(&entities(&[2]));
+    }
+}
@@ -1,12 +1,12 @@
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
 use core::fmt::Debug;
-use core::hash::Hash;
+use core::hash::{BuildHasher, Hash};
 use core::ops::Deref;
 use vc_reflect::derive::Reflect;
 
 use vc_os::sync::{PoisonError, RwLock};
-use vc_utils::hash::{FixedHashState, HashSet};
+use vc_utils::hash::{FixedHashState, HashTable};
 
 // -----------------------------------------------------------------------------
 // Internable
@@ -93,7 +93,11 @@ impl<T: ?Sized + Internable> From<&Interned<T>> for Interned<T> {
 // -----------------------------------------------------------------------------
 // Interner
 
-pub struct Interner<T: ?Sized + 'static>(RwLock<HashSet<&'static T>>);
+/// A thread-safe store of leaked values, handing out [`Interned`] handles.
+///
+/// Each entry keeps the hash it was inserted with, so values interned through
+/// [`intern_by`](Interner::intern_by) stay reachable under their custom hash.
+pub struct Interner<T: ?Sized + 'static>(RwLock<HashTable<(u64, &'static T)>>);
 
 impl<T: ?Sized> Default for Interner<T> {
     #[inline]
@@ -105,31 +109,101 @@ impl<T: ?Sized> Default for Interner<T> {
 impl<T: ?Sized> Interner<T> {
     /// Creates a new empty interner
     pub const fn new() -> Self {
-        Self(RwLock::new(HashSet::with_hasher(FixedHashState)))
+        Self(RwLock::new(HashTable::new()))
     }
 }
 
 impl<T: Internable + ?Sized> Interner<T> {
     /// Return the [`Interned<T>`] corresponding to `value`.
     pub fn intern(&self, value: &T) -> Interned<T> {
+        let hash = FixedHashState.hash_one(value);
+        self.intern_by(value, hash, T::eq)
+    }
+
+    /// Return the [`Interned<T>`] corresponding to `value`, using a custom
+    /// notion of equality.
+    ///
+    /// `hash` must be consistent with `eq`: values that `eq` considers equal
+    /// must be passed the same `hash`. Any two such values yield the same
+    /// handle, and the first one interned is the one that gets stored.
+    ///
+    /// An interner should be used either with [`intern`](Self::intern) or with
+    /// a single custom equality, mixing both gives unspecified (but safe) dedup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::BuildHasher;
+    /// use vc_ecs::intern::Interner;
+    /// use vc_utils::hash::FixedHashState;
+    ///
+    /// fn intern_label(interner: &Interner<str>, label: &str) -> &'static str {
+    ///     let hash = FixedHashState.hash_one(label.to_ascii_lowercase());
+    ///     interner.intern_by(label, hash, str::eq_ignore_ascii_case).0
+    /// }
+    ///
+    /// let interner = Interner::new();
+    /// assert_eq!(intern_label(&interner, "Fire"), "Fire");
+    /// assert_eq!(intern_label(&interner, "fire"), "Fire");
+    /// ```
+    pub fn intern_by(&self, value: &T, hash: u64, eq: impl Fn(&T, &T) -> bool) -> Interned<T> {
         {
-            let set = self.0.read().unwrap_or_else(PoisonError::into_inner);
+            let table = self.0.read().unwrap_or_else(PoisonError::into_inner);
 
-            if let Some(value) = set.get(value) {
-                return Interned(*value);
+            if let Some(&(_, value)) = table.find(hash, |&(_, x)| eq(x, value)) {
+                return Interned(value);
             }
         }
 
         {
-            let mut set = self.0.write().unwrap_or_else(PoisonError::into_inner);
+            let mut table = self.0.write().unwrap_or_else(PoisonError::into_inner);
 
-            if let Some(value) = set.get(value) {
-                Interned(*value)
+            if let Some(&(_, value)) = table.find(hash, |&(_, x)| eq(x, value)) {
+                Interned(value)
             } else {
                 let leaked = value.leak();
-                set.insert(leaked);
+                table.insert_unique(hash, (hash, leaked), |&(hash, _)| hash);
                 Interned(leaked)
             }
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::hash::BuildHasher;
+
+    use vc_utils::hash::FixedHashState;
+
+    use super::{Interned, Interner};
+
+    fn intern_ignore_case(interner: &Interner<str>, label: &str) -> Interned<str> {
+        let hash = FixedHashState.hash_one(label.to_ascii_lowercase());
+        interner.intern_by(label, hash, str::eq_ignore_ascii_case)
+    }
+
+    #[test]
+    fn intern_dedups_by_value() {
+        let interner = Interner::<str>::new();
+        let a = interner.intern("Fire");
+        let b = interner.intern(&alloc::string::String::from("Fire"));
+        assert_eq!(a, b);
+        assert_ne!(a, interner.intern("fire"));
+    }
+
+    #[test]
+    fn intern_by_custom_eq() {
+        let interner = Interner::<str>::new();
+        let fire = intern_ignore_case(&interner, "Fire");
+        let lower = intern_ignore_case(&interner, "fire");
+        let ice = intern_ignore_case(&interner, "Ice");
+
+        assert_eq!(fire, lower);
+        assert_eq!(&*lower, "Fire");
+        assert_ne!(fire, ice);
+        assert_eq!(intern_ignore_case(&interner, "ICE"), ice);
+    }
+}
@@ -56,6 +56,7 @@ pub mod component;
 pub mod entity;
 pub mod event;
 pub mod lifecycle;
+pub mod message;
 pub mod relationship;
 pub mod storage;
 pub mod world;
@@ -7,11 +7,23 @@ use crate::world::DeferredWorld;
 // -----------------------------------------------------------------------------
 // HookContext
 
+/// Context provided to a [`ComponentHook`].
+///
+/// A single hook function may be shared by several components, so the
+/// context carries the [`ComponentId`] that triggered it.
 #[derive(Clone, Copy, Debug)]
 pub struct HookContext {
+    /// The [`Entity`] this hook was invoked for.
     pub entity: Entity,
+    /// The [`ComponentId`] this hook was invoked for.
     pub component_id: ComponentId,
+    /// The caller location of the structural operation (`spawn`, `insert`,
+    /// `remove`, ...) that triggered this hook, not the location where the
+    /// hook was registered.
+    ///
+    /// Only available with the `debug` feature or `debug_assertions`.
     pub caller: DebugLocation,
+    /// Configures how relationship hooks will run.
     pub relationship_hook_mode: RelationshipHookMode,
 }
 
@@ -200,3 +212,60 @@ impl ComponentHooks {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::HookContext;
+    use crate::component::{Component, ComponentId};
+    use crate::entity::Entity;
+    use crate::relationship::RelationshipHookMode;
+    use crate::resource::Resource;
+    use crate::utils::DebugLocation;
+    use crate::world::{DeferredWorld, World};
+
+    #[derive(Default)]
+    struct Inserted(Vec<(ComponentId, Entity)>);
+    impl Resource for Inserted {}
+
+    fn record_insert(mut world: DeferredWorld, context: HookContext) {
+        let record = (context.component_id, context.entity);
+        world.resource_mut::<Inserted>().0.push(record);
+    }
+
+    #[derive(Component)]
+    #[component(on_insert = record_insert)]
+    struct Health;
+
+    #[derive(Component)]
+    #[component(on_insert = record_insert)]
+    struct Mana;
+
+    #[test]
+    fn shared_hook_sees_triggering_component() {
+        let mut world = World::new();
+        world.insert_resource(Inserted::default());
+        let health = world.component_id_or_register::<Health>();
+        let mana = world.component_id_or_register::<Mana>();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+
+        for (id, entity) in [(health, first), (mana, second)] {
+            let hook = world.components().get_info(id).unwrap().hooks().on_insert;
+            let context = HookContext {
+                entity,
+                component_id: id,
+                caller: DebugLocation::caller(),
+                relationship_hook_mode: RelationshipHookMode::Run,
+            };
+            hook.unwrap()(DeferredWorld::from(&mut world), context);
+        }
+
+        let inserted = &world.get_resource::<Inserted>().unwrap().0;
+        assert_eq!(inserted, &[(health, first), (mana, second)]);
+    }
+}
@@ -0,0 +1,32 @@
+// -----------------------------------------------------------------------------
+// Message
+
+/// A buffered message that systems write and read.
+///
+/// Usually implemented with `#[derive(Message)]`.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::message::Message;
+///
+/// #[derive(Message)]
+/// struct Spawned;
+///
+/// #[derive(Message)]
+/// #[message(shared)]
+/// struct Collided;
+///
+/// assert!(!Spawned::SHARED);
+/// assert!(Collided::SHARED);
+/// ```
+pub trait Message: Send + Sync + 'static {
+    /// Whether readers borrow the message instead of taking ownership of it.
+    ///
+    /// A shared message is read by reference, so every reader sees it without
+    /// cloning, and it is dropped only once all readers of the frame had the
+    /// chance to read it. Set with `#[message(shared)]`.
+    const SHARED: bool = false;
+}
+
+pub use vc_ecs_derive::Message;
@@ -0,0 +1,128 @@
+use alloc::boxed::Box;
+
+use vc_reflect::Reflect;
+use vc_reflect::info::{TypePath, Typed};
+use vc_reflect::registry::FromType;
+
+use crate::world::{FromWorld, World};
+
+// -----------------------------------------------------------------------------
+// TypeTraitFromWorld
+
+/// A container providing [`FromWorld`] support for reflected types.
+///
+/// This lets a loader construct a value, e.g. a resource, knowing only its
+/// registered type name.
+///
+/// # Examples
+///
+/// ```
+/// use vc_ecs::reflect::TypeTraitFromWorld;
+/// use vc_ecs::world::World;
+/// use vc_reflect::registry::TypeRegistry;
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register_type_trait::<String, TypeTraitFromWorld>();
+///
+/// let from_world = registry
+///     .get_with_type_name("String").unwrap()
+///     .get_trait::<TypeTraitFromWorld>().unwrap();
+///
+/// let value = from_world.from_world(&mut World::new());
+/// assert_eq!(value.take::<String>().unwrap(), "");
+/// ```
+#[derive(Clone)]
+pub struct TypeTraitFromWorld {
+    func: fn(&mut World) -> Box<dyn Reflect>,
+}
+
+impl TypeTraitFromWorld {
+    /// Call T's [`FromWorld`].
+    #[inline(always)]
+    pub fn from_world(&self, world: &mut World) -> Box<dyn Reflect> {
+        (self.func)(world)
+    }
+}
+
+impl<T: FromWorld + Typed + Reflect> FromType<T> for TypeTraitFromWorld {
+    fn from_type() -> Self {
+        Self {
+            func: |world| Box::new(T::from_world(world)),
+        }
+    }
+}
+
+impl TypePath for TypeTraitFromWorld {
+    #[inline(always)]
+    fn type_path() -> &'static str {
+        "vc_ecs::reflect::TypeTraitFromWorld"
+    }
+
+    #[inline(always)]
+    fn type_name() -> &'static str {
+        "TypeTraitFromWorld"
+    }
+
+    #[inline(always)]
+    fn type_ident() -> &'static str {
+        "TypeTraitFromWorld"
+    }
+
+    #[inline(always)]
+    fn module_path() -> Option<&'static str> {
+        Some("vc_ecs::reflect")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use vc_reflect::derive::Reflect;
+    use vc_reflect::registry::TypeRegistry;
+
+    use super::TypeTraitFromWorld;
+    use crate::resource::Resource;
+    use crate::world::{FromWorld, World};
+
+    #[derive(Default)]
+    struct Difficulty(u32);
+
+    impl Resource for Difficulty {}
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Spawner {
+        wave_size: u32,
+    }
+
+    impl Resource for Spawner {}
+
+    impl FromWorld for Spawner {
+        fn from_world(world: &mut World) -> Self {
+            let difficulty = world.get_resource_or_init::<Difficulty>().0;
+            Spawner {
+                wave_size: 10 * difficulty,
+            }
+        }
+    }
+
+    #[test]
+    fn construct_by_type_name() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Spawner>();
+        registry.register_type_trait::<Spawner, TypeTraitFromWorld>();
+
+        let mut world = World::new();
+        world.insert_resource(Difficulty(3));
+
+        let from_world = registry
+            .get_with_type_name("Spawner")
+            .unwrap()
+            .get_trait::<TypeTraitFromWorld>()
+            .unwrap();
+        let value = from_world.from_world(&mut world);
+
+        assert_eq!(value.take::<Spawner>().unwrap(), Spawner { wave_size: 30 });
+    }
+}
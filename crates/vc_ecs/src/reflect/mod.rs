@@ -3,6 +3,9 @@ use core::ops::{Deref, DerefMut};
 use vc_reflect::registry::TypeRegistryArc;
 
 mod component;
+mod from_world;
+
+pub use from_world::TypeTraitFromWorld;
 
 #[derive(Clone, Default)]
 pub struct AppTypeRegistry(TypeRegistryArc);
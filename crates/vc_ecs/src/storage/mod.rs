@@ -10,7 +10,8 @@ mod utils;
 // -----------------------------------------------------------------------------
 // Internal API
 
-use utils::{AbortOnDrop, BlobArray, VecCopyRemove, VecSwapRemove};
+pub(crate) use utils::BlobArray;
+use utils::{AbortOnDrop, VecCopyRemove, VecSwapRemove};
 
 // -----------------------------------------------------------------------------
 // Exports
@@ -34,6 +35,16 @@ pub struct Storages {
 }
 
 impl Storages {
+    #[inline]
+    pub fn empty() -> Self {
+        Self {
+            sparse_sets: SparseSets::empty(),
+            tables: Tables::empty(),
+            resources: Resources::empty(),
+            non_send_resources: NoSendResources::empty(),
+        }
+    }
+
     #[inline]
     pub fn prepare_component(&mut self, component: &crate::component::ComponentInfo) {
         match component.storage_type() {
@@ -101,6 +101,7 @@ impl ResourceData {
     #[inline]
     pub fn get_mut(&mut self, last_run: Tick, this_run: Tick) -> Option<MutUntyped<'_>> {
         if self.is_present {
+            let layout = self.data.layout();
             let value = unsafe { self.data.get_item_mut(Self::INDEX) };
             let cells = ComponentTickCells {
                 added: &self.added_tick,
@@ -108,7 +109,7 @@ impl ResourceData {
                 changed_by: self.changed_by.as_ref(),
             };
             let ticks = unsafe { ComponentTicksMut::from_tick_cells(cells, last_run, this_run) };
-            Some(MutUntyped { value, ticks })
+            Some(MutUntyped::new(value, ticks, layout))
         } else {
             None
         }
@@ -128,6 +129,7 @@ impl ResourceData {
         } else {
             unsafe {
                 self.data.init_item(Self::INDEX, value);
+                *self.added_tick.deref_mut() = change_tick;
             }
             self.is_present = true;
         }
@@ -247,10 +249,11 @@ impl NoSendResourceData {
             #[inline(never)]
             fn invalid_access(this: &NoSendResourceData) -> ! {
                 panic!(
-                    "Attempted to access or drop non-send resource {} from thread {:?} on a thread {:?}.",
+                    "Attempted to access or drop non-send resource {} on thread {:?}, \
+                    but it belongs to thread {:?}.",
                     this.name,
+                    std::thread::current().id(),
                     this.thread_id,
-                    std::thread::current().id()
                 );
             }
 
@@ -339,6 +342,7 @@ impl NoSendResourceData {
     pub fn get_mut(&mut self, last_run: Tick, this_run: Tick) -> Option<MutUntyped<'_>> {
         if self.is_present {
             self.validate_access();
+            let layout = self.data.layout();
             let value = unsafe { self.data.get_item_mut(Self::INDEX) };
             let cells = ComponentTickCells {
                 added: &self.added_tick,
@@ -346,7 +350,7 @@ impl NoSendResourceData {
                 changed_by: self.changed_by.as_ref(),
             };
             let ticks = unsafe { ComponentTicksMut::from_tick_cells(cells, last_run, this_run) };
-            Some(MutUntyped { value, ticks })
+            Some(MutUntyped::new(value, ticks, layout))
         } else {
             None
         }
@@ -368,6 +372,7 @@ impl NoSendResourceData {
             self.init_thread_id();
             unsafe {
                 self.data.init_item(Self::INDEX, value);
+                *self.added_tick.deref_mut() = change_tick;
             }
             self.is_present = true;
         }
@@ -454,3 +459,52 @@ impl NoSendResourceData {
         self.changed_tick.get_mut().check_age(check.tick());
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::alloc::Layout;
+    use std::thread;
+
+    use vc_ptr::OwningPtr;
+
+    use super::NoSendResourceData;
+    use crate::tick::Tick;
+    use crate::utils::{DebugLocation, DebugName};
+
+    /// Smuggles the storage to another thread, which is exactly what the
+    /// thread check must catch.
+    struct AssertSync<'a>(&'a NoSendResourceData);
+    unsafe impl Sync for AssertSync<'_> {}
+
+    #[test]
+    fn non_send_access_from_other_thread_panics() {
+        let mut data =
+            NoSendResourceData::new(DebugName::type_name::<u32>(), Layout::new::<u32>(), None);
+        OwningPtr::make(7_u32, |ptr| unsafe {
+            data.insert(ptr, Tick::new(1), DebugLocation::caller());
+        });
+        assert_eq!(unsafe { *data.get_data().unwrap().as_ref::<u32>() }, 7);
+
+        let shared = AssertSync(&data);
+        let owner = thread::current().id();
+        let payload = thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let shared = &shared;
+                    shared.0.get_data().is_some()
+                })
+                .join()
+                .unwrap_err()
+        });
+
+        let message = payload.downcast_ref::<alloc::string::String>().unwrap();
+        assert!(message.contains("non-send resource"));
+        assert!(message.contains(&alloc::format!(
+            "but it belongs to thread {:?}",
+            Some(owner)
+        )));
+    }
+}
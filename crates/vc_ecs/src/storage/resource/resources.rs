@@ -1,7 +1,7 @@
 #![expect(unsafe_code, reason = "original implementation need unsafe codes.")]
 
 use super::{NoSendResourceData, ResourceData};
-use crate::component::ComponentId;
+use crate::component::{ComponentId, ComponentTicks};
 use crate::storage::SparseSet;
 use crate::tick::CheckTicks;
 
@@ -13,6 +13,13 @@ pub struct Resources {
 }
 
 impl Resources {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self {
+            resources: SparseSet::empty(),
+        }
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.resources.len()
@@ -38,6 +45,14 @@ impl Resources {
         self.resources.get_mut(component_id)
     }
 
+    /// Returns the change ticks of the resource without borrowing its value.
+    ///
+    /// Returns `None` if the resource is not initialized or has no value.
+    #[inline]
+    pub fn get_ticks(&self, component_id: ComponentId) -> Option<ComponentTicks> {
+        self.resources.get(component_id)?.get_component_ticks()
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.resources.clear();
@@ -59,6 +74,13 @@ pub struct NoSendResources {
 }
 
 impl NoSendResources {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self {
+            resources: SparseSet::empty(),
+        }
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.resources.len()
@@ -84,6 +106,14 @@ impl NoSendResources {
         self.resources.get_mut(component_id)
     }
 
+    /// Returns the change ticks of the resource without borrowing its value.
+    ///
+    /// Returns `None` if the resource is not initialized or has no value.
+    #[inline]
+    pub fn get_ticks(&self, component_id: ComponentId) -> Option<ComponentTicks> {
+        self.resources.get(component_id)?.get_component_ticks()
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.resources.clear();
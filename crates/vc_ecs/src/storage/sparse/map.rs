@@ -106,4 +106,21 @@ impl<I: SparseIndex, V> SparseArray<I, V> {
     pub fn clear(&mut self) {
         self.values.clear();
     }
+
+    /// Returns the number of slots the array can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// Drops trailing empty slots and shrinks the allocation to fit.
+    pub fn shrink_to_fit(&mut self) {
+        let len = self
+            .values
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |index| index + 1);
+        self.values.truncate(len);
+        self.values.shrink_to_fit();
+    }
 }
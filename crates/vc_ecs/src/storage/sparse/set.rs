@@ -137,12 +137,50 @@ impl<I: SparseIndex, V> SparseSet<I, V> {
         }
     }
 
+    /// Removes the value at `index`, moving the last value into its place.
+    pub fn remove(&mut self, index: I) -> Option<V> {
+        let dense_index = self.sparse.remove(index)?.get() as usize;
+        let is_last = dense_index == self.dense.len() - 1;
+
+        let value = self.dense.swap_remove(dense_index);
+        self.indices.swap_remove(dense_index);
+
+        if !is_last {
+            let swapped = unsafe { *self.indices.get_unchecked(dense_index) };
+            self.sparse.insert(swapped, unsafe {
+                NonMaxU32::new_unchecked(dense_index as u32)
+            });
+        }
+
+        Some(value)
+    }
+
     pub fn clear(&mut self) {
         self.dense.clear();
         self.indices.clear();
         self.sparse.clear();
     }
 
+    /// Shrinks the allocations to fit the current values.
+    ///
+    /// The sparse array is truncated after the highest index still present,
+    /// so memory held for a large index that was removed is reclaimed.
+    pub fn shrink_to_fit(&mut self) {
+        self.dense.shrink_to_fit();
+        self.indices.shrink_to_fit();
+        self.sparse.shrink_to_fit();
+    }
+
+    /// Estimates the heap memory used by this set, in bytes.
+    ///
+    /// Counts the allocated capacity of the dense values, the dense indices
+    /// and the sparse array, but not memory owned by the values themselves.
+    pub fn heap_bytes(&self) -> usize {
+        self.dense.capacity() * size_of::<V>()
+            + self.indices.capacity() * size_of::<I>()
+            + self.sparse.capacity() * size_of::<Option<NonMaxU32>>()
+    }
+
     pub fn get_or_insert_with(&mut self, index: I, func: impl FnOnce() -> V) -> &mut V {
         if let Some(dense_index) = self.sparse.get_copied(index) {
             // SAFETY: dense indices stored in self.sparse always exist
@@ -167,3 +205,48 @@ impl<I: SparseIndex, V> SparseSet<I, V> {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use super::SparseSet;
+    use crate::component::ComponentId;
+
+    fn id(index: u32) -> ComponentId {
+        ComponentId::new(NonZeroU32::new(index).unwrap())
+    }
+
+    #[test]
+    fn remove_keeps_moved_value_reachable() {
+        let mut set = SparseSet::<ComponentId, &str>::empty();
+        set.insert(id(1), "a");
+        set.insert(id(2), "b");
+        set.insert(id(3), "c");
+
+        assert_eq!(set.remove(id(1)), Some("a"));
+        assert_eq!(set.remove(id(1)), None);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get(id(2)), Some(&"b"));
+        assert_eq!(set.get(id(3)), Some(&"c"));
+    }
+
+    #[test]
+    fn shrink_after_high_index() {
+        let mut set = SparseSet::<ComponentId, u64>::empty();
+        set.insert(id(1), 1);
+        set.insert(id(10_000), 2);
+        let before = set.heap_bytes();
+        assert!(set.sparse.capacity() > 10_000);
+
+        set.remove(id(10_000));
+        set.shrink_to_fit();
+
+        assert!(set.sparse.capacity() < 10_000);
+        assert!(set.heap_bytes() < before);
+        assert_eq!(set.get(id(1)), Some(&1));
+    }
+}
@@ -72,12 +72,15 @@ use crate::component::ComponentInfo;
 
 impl SparseSets {
     pub fn prepare_component(&mut self, info: &ComponentInfo) {
-        if self.sets.get_raw_index(info.id()).is_none() {
-            self.sets.insert(
-                info.id(),
-                SparseComponent::empty(info.layout(), info.drop_fn()),
-            );
-        }
+        self.get_or_insert(info);
+    }
+
+    /// Returns the storage of the component described by `info`,
+    /// creating it from the component's layout and drop function if absent.
+    pub fn get_or_insert(&mut self, info: &ComponentInfo) -> &mut SparseComponent {
+        self.sets.get_or_insert_with(info.id(), || {
+            SparseComponent::empty(info.layout(), info.drop_fn())
+        })
     }
 
     pub fn get_raw_index_or_insert(&mut self, info: &ComponentInfo) -> u32 {
@@ -91,3 +94,53 @@ impl SparseSets {
         )
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::alloc::Layout;
+    use core::num::NonZeroU32;
+
+    use vc_ptr::OwningPtr;
+
+    use super::SparseSets;
+    use crate::component::{Component, Mutable};
+    use crate::entity::EntityId;
+    use crate::storage::StorageType;
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+    use crate::world::World;
+
+    struct Velocity([f32; 3]);
+    impl Component for Velocity {
+        const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+        type Mutability = Mutable;
+    }
+
+    #[test]
+    fn get_or_insert_creates_once() {
+        let mut world = World::new();
+        let id = world
+            .components_registrator()
+            .register_component::<Velocity>();
+        let info = world.components().get_info(id).unwrap();
+        let entity = EntityId::new(NonZeroU32::new(1).unwrap());
+
+        let mut sets = SparseSets::empty();
+        assert!(sets.get_raw_index(id).is_none());
+
+        let set = sets.get_or_insert(info);
+        assert_eq!(set.item_layout(), Layout::new::<Velocity>());
+        OwningPtr::make(Velocity([1.0, 2.0, 3.0]), |ptr| {
+            set.insert(entity, ptr, Tick::new(1), DebugLocation::caller());
+        });
+
+        let set = sets.get_or_insert(info);
+        assert_eq!(set.entity_count(), 1);
+        let value = unsafe { set.get_component(entity).unwrap().as_ref::<Velocity>() };
+        assert_eq!(value.0, [1.0, 2.0, 3.0]);
+        assert_eq!(sets.component_count(), 1);
+    }
+}
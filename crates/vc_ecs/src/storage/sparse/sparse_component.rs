@@ -61,6 +61,12 @@ impl SparseComponent {
         self.entities.capacity()
     }
 
+    /// Returns the layout of a single component value.
+    #[inline]
+    pub fn item_layout(&self) -> Layout {
+        self.column.item_layout()
+    }
+
     pub fn clear_entities(&mut self) {
         let len = self.entity_count();
         self.entities.clear();
@@ -9,6 +9,11 @@ use core::hash;
 pub struct TableId(u32);
 
 impl TableId {
+    /// The table without columns, used by the [`ArchetypeId::EMPTY`] archetype.
+    ///
+    /// It always exists, spawned-empty entities are placed here.
+    ///
+    /// [`ArchetypeId::EMPTY`]: crate::archetype::ArchetypeId::EMPTY
     pub const EMPTY: TableId = TableId(0);
 
     #[inline(always)]
@@ -140,11 +140,20 @@ impl Table {
         }
     }
 
+    /// Returns `true` if this table has a column for the component `id`.
     #[inline]
     pub fn contains_component(&self, id: ComponentId) -> bool {
         self.sparse.contains_key(&id)
     }
 
+    /// Returns the ids of the components stored in this table, in column order.
+    ///
+    /// The position of an id is its raw column index, see [`Table::get_raw_index`].
+    #[inline(always)]
+    pub fn components(&self) -> &[ComponentId] {
+        &self.indices
+    }
+
     #[inline]
     pub fn get_raw_index(&self, id: ComponentId) -> Option<u32> {
         self.sparse.get(&id).copied()
@@ -342,6 +351,37 @@ impl Table {
         }
     }
 
+    /// Swaps the rows `a` and `b`, including every component value, its ticks
+    /// and the stored [`Entity`].
+    ///
+    /// The table does not know where its entities are referenced from, so the
+    /// [`EntityLocation`](crate::entity::EntityLocation) and archetype table row
+    /// of both entities are left stale. Use [`World::swap_table_rows`], which
+    /// updates them in the same call.
+    ///
+    /// [`World::swap_table_rows`]: crate::world::World::swap_table_rows
+    ///
+    /// # Panics
+    ///
+    /// Panics if either row is out of bounds.
+    pub(crate) fn swap_rows(&mut self, a: TableRow, b: TableRow) {
+        let (a, b) = (a.index(), b.index());
+        let len = self.entity_count();
+        assert!(a < len && b < len, "table row out of bounds");
+
+        if a == b {
+            return;
+        }
+
+        self.entities.swap(a, b);
+        for column in &mut self.columns {
+            // SAFETY: both rows are distinct and initialized.
+            unsafe {
+                column.swap_nonoverlapping(a, b);
+            }
+        }
+    }
+
     #[inline]
     unsafe fn alloc_columns(&mut self, new_capacity: NonZeroUsize) {
         let abort_guard = AbortOnDrop;
@@ -390,11 +430,24 @@ impl Table {
         }
     }
 
+    /// Reserves a new row for `entity` at the end of the table, growing the
+    /// columns if needed, and returns it.
+    ///
+    /// The ticks of the new row are reset, but the component values are left
+    /// uninitialized, write them with [`Column::init_item`] at [`TableRow::index`].
+    ///
+    /// # Safety
+    ///
+    /// - `entity` must not already be stored in this table.
+    /// - Every column must be initialized at the returned row before the table
+    ///   is dropped, cleared, or the row is read, moved or removed.
+    ///   The table treats the row as initialized from now on, so a panic
+    ///   before all columns are written will drop uninitialized values.
     pub unsafe fn allocate(&mut self, entity: Entity) -> TableRow {
         // SAFETY: `0 < EntityId < u32::MAX`, so `len < u32::MAX`
         let len = self.entity_count();
 
-        if len == self.entity_count() {
+        if len == self.capacity() {
             self.reserve_one();
         }
 
@@ -565,3 +618,125 @@ impl Table {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use core::alloc::Layout;
+    use core::num::NonZeroU32;
+
+    use nonmax::NonMaxU32;
+    use vc_ptr::OwningPtr;
+
+    use super::TableBuilder;
+    use crate::entity::EntityAllocator;
+    use crate::storage::TableRow;
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+
+    unsafe fn drop_string(ptr: OwningPtr<'_>) {
+        unsafe { ptr.drop_as::<String>() }
+    }
+
+    fn row(index: u32) -> TableRow {
+        TableRow::new(NonMaxU32::new(index).unwrap())
+    }
+
+    #[test]
+    fn column_presence() {
+        let id = |index| crate::component::ComponentId::new(NonZeroU32::new(index).unwrap());
+        let mut builder = TableBuilder::new(2);
+        builder.insert(id(3), Layout::new::<u32>(), None);
+        builder.insert(id(1), Layout::new::<u8>(), None);
+        let table = builder.build();
+
+        assert!(table.contains_component(id(1)));
+        assert!(table.contains_component(id(3)));
+        assert!(!table.contains_component(id(2)));
+        assert_eq!(table.components(), [id(3), id(1)]);
+        for (raw_index, &component) in table.components().iter().enumerate() {
+            assert_eq!(table.get_raw_index(component), Some(raw_index as u32));
+        }
+    }
+
+    #[test]
+    fn allocate_and_write_columns() {
+        let id = |index| crate::component::ComponentId::new(NonZeroU32::new(index).unwrap());
+        let mut builder = TableBuilder::new(2);
+        let name = builder.insert(id(1), Layout::new::<String>(), Some(drop_string));
+        let level = builder.insert(id(2), Layout::new::<u32>(), None);
+        let mut table = builder.build();
+
+        let entity = EntityAllocator::new().alloc();
+        let table_row = unsafe { table.allocate(entity) };
+        assert_eq!(table_row, row(0));
+        assert_eq!(table.entities(), [entity]);
+
+        let tick = Tick::new(3);
+        OwningPtr::make(String::from("slime"), |ptr| unsafe {
+            table.get_column_mut(name).init_item(
+                table_row.index(),
+                ptr,
+                tick,
+                DebugLocation::caller(),
+            );
+        });
+        OwningPtr::make(7_u32, |ptr| unsafe {
+            table.get_column_mut(level).init_item(
+                table_row.index(),
+                ptr,
+                tick,
+                DebugLocation::caller(),
+            );
+        });
+
+        unsafe {
+            assert_eq!(
+                table.get_component(name, table_row).as_ref::<String>(),
+                "slime"
+            );
+            assert_eq!(*table.get_component(level, table_row).as_ref::<u32>(), 7);
+            let ticks = table.get_component_ticks(level, table_row).unwrap();
+            assert_eq!(ticks.added, tick);
+            assert_eq!(ticks.changed, tick);
+        }
+    }
+
+    #[test]
+    fn swap_rows_keeps_values_ticks_and_entities_together() {
+        let id = crate::component::ComponentId::new(NonZeroU32::new(1).unwrap());
+        let mut builder = TableBuilder::new(1);
+        let raw_index = builder.insert(id, Layout::new::<String>(), Some(drop_string));
+        let mut table = builder.build();
+
+        let allocator = EntityAllocator::new();
+        let spawned: alloc::vec::Vec<_> = allocator.alloc_many(3).collect();
+
+        for (index, &entity) in spawned.iter().enumerate() {
+            let table_row = unsafe { table.allocate(entity) };
+            let value = index.to_string();
+            let tick = Tick::new(index as u32 + 1);
+            OwningPtr::make(value, |ptr| unsafe {
+                table.get_column_mut(raw_index).init_item(
+                    table_row.index(),
+                    ptr,
+                    tick,
+                    DebugLocation::caller(),
+                );
+            });
+        }
+
+        table.swap_rows(row(0), row(2));
+
+        assert_eq!(table.entities(), [spawned[2], spawned[1], spawned[0]]);
+        for (index, table_row) in [row(2), row(1), row(0)].into_iter().enumerate() {
+            let value = unsafe { table.get_component(raw_index, table_row).as_ref::<String>() };
+            let ticks = unsafe { table.get_component_ticks(raw_index, table_row) }.unwrap();
+            assert_eq!(*value, index.to_string());
+            assert_eq!(ticks.added, Tick::new(index as u32 + 1));
+        }
+    }
+}
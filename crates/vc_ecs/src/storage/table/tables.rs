@@ -105,9 +105,46 @@ impl Tables {
 // Create Table From Components
 
 use crate::component::Components;
+use crate::storage::StorageType;
 use crate::utils::DebugCheckedUnwrap;
 
 impl Tables {
+    /// Returns the [`TableId`] of the table storing exactly `component_ids`,
+    /// creating the table if it does not exist yet.
+    ///
+    /// The order of `component_ids` does not matter and duplicates are ignored,
+    /// so every permutation of the same set resolves to the same table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the ids is not registered in `components`,
+    /// or is not stored in [`StorageType::Table`].
+    pub fn get_id_or_insert(
+        &mut self,
+        component_ids: &[ComponentId],
+        components: &Components,
+    ) -> TableId {
+        let mut ids = Vec::from(component_ids);
+        ids.sort_unstable();
+        ids.dedup();
+
+        if let Some(&table_id) = self.table_ids.get(ids.as_slice()) {
+            return table_id;
+        }
+
+        for &id in &ids {
+            let info = components.get_info(id);
+            assert!(info.is_some(), "component {id:?} is not registered");
+            assert!(
+                info.is_some_and(|info| info.storage_type() == StorageType::Table),
+                "component {id:?} is not stored in tables",
+            );
+        }
+
+        // SAFETY: the ids are sorted, unique and registered in `components`.
+        unsafe { self.get_id_and_raw_indecies_or_insert(&ids, components).0 }
+    }
+
     pub unsafe fn get_id_and_raw_indecies_or_insert(
         &mut self,
         ids: &[ComponentId],
@@ -153,3 +190,71 @@ impl Tables {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::Tables;
+    use crate::component::Mutable;
+    use crate::component::{Component, ComponentIdGenerator, Components, ComponentsRegistrator};
+    use crate::storage::{StorageType, TableId};
+
+    struct A;
+    struct B;
+    struct Sparse;
+
+    impl Component for A {
+        const STORAGE_TYPE: StorageType = StorageType::Table;
+        type Mutability = Mutable;
+    }
+
+    impl Component for B {
+        const STORAGE_TYPE: StorageType = StorageType::Table;
+        type Mutability = Mutable;
+    }
+
+    impl Component for Sparse {
+        const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+        type Mutability = Mutable;
+    }
+
+    #[test]
+    fn get_id_or_insert_is_order_independent() {
+        let mut components = Components::empty();
+        let mut generator = ComponentIdGenerator::new();
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut components, &mut generator) };
+        let a = registrator.register_component::<A>();
+        let b = registrator.register_component::<B>();
+
+        let mut tables = Tables::empty();
+        assert_eq!(tables.get_id_or_insert(&[], &components), TableId::EMPTY);
+
+        let ab = tables.get_id_or_insert(&[a, b], &components);
+        assert_ne!(ab, TableId::EMPTY);
+        assert_eq!(tables.get_id_or_insert(&[a, b], &components), ab);
+        assert_eq!(tables.get_id_or_insert(&[b, a], &components), ab);
+        assert_eq!(tables.get_id_or_insert(&[b, a, b], &components), ab);
+        assert_eq!(tables.table_count(), 2);
+
+        let only_a = tables.get_id_or_insert(&[a], &components);
+        assert_ne!(only_a, ab);
+        assert!(tables[only_a].contains_component(a));
+        assert!(!tables[only_a].contains_component(b));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not stored in tables")]
+    fn get_id_or_insert_rejects_sparse_set_components() {
+        let mut components = Components::empty();
+        let mut generator = ComponentIdGenerator::new();
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut components, &mut generator) };
+        let a = registrator.register_component::<A>();
+        let sparse = registrator.register_component::<Sparse>();
+
+        Tables::empty().get_id_or_insert(&[a, sparse], &components);
+    }
+}
@@ -228,6 +228,19 @@ impl BlobArray {
         }
     }
 
+    /// Swaps the bytes of the items at `a` and `b`, which must be distinct.
+    ///
+    /// This is a plain byte swap, so it can not panic half way through.
+    #[inline(always)]
+    pub const unsafe fn swap_nonoverlapping(&mut self, a: usize, b: usize) {
+        let size = self.item_layout.size();
+        unsafe {
+            let a = self.data.as_ptr().byte_add(size * a);
+            let b = self.data.as_ptr().byte_add(size * b);
+            core::ptr::swap_nonoverlapping::<u8>(a, b, size);
+        }
+    }
+
     #[inline]
     pub unsafe fn swap_remove_and_drop_nonoverlapping(&mut self, index: usize, last_index: usize) {
         let drop_fn = self.drop_fn;
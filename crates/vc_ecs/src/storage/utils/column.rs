@@ -114,6 +114,54 @@ impl Column {
         }
     }
 
+    /// Converts every value of the column to a new item layout.
+    ///
+    /// Allocates new storage of `new_layout` with the same capacity, calls
+    /// `convert` for each of the first `len` rows with the old value and the
+    /// uninitialized new slot, then drops the old values and frees the old
+    /// storage. Ticks and change locations are left untouched.
+    ///
+    /// This is a dangerous migration primitive for offline tooling: nothing
+    /// else in the world learns about the new layout, so the caller must also
+    /// update the component's descriptor before the column is read again.
+    ///
+    /// If `convert` panics, the old values are kept and the new storage leaks.
+    ///
+    /// # Safety
+    ///
+    /// - `current_capacity` must be the capacity of the column, and the first
+    ///   `len` rows must be initialized.
+    /// - `convert` must fully initialize its second argument as a valid value
+    ///   of `new_layout`, and must not move out of its first argument.
+    /// - `new_drop_fn` must be valid to call on the values written by `convert`.
+    pub unsafe fn migrate(
+        &mut self,
+        current_capacity: usize,
+        len: usize,
+        new_layout: Layout,
+        new_drop_fn: Option<unsafe fn(OwningPtr<'_>)>,
+        mut convert: impl FnMut(Ptr<'_>, PtrMut<'_>),
+    ) {
+        cfg::debug! {
+            assert!(self.capacity == current_capacity);
+            assert!(len <= self.capacity);
+        }
+
+        unsafe {
+            let mut data = BlobArray::with_capacity(new_layout, new_drop_fn, current_capacity);
+            for index in 0..len {
+                convert(self.data.get_item(index), data.get_item_mut(index));
+            }
+            self.data.dealloc(current_capacity, len);
+            self.data = data;
+        }
+    }
+
+    #[inline(always)]
+    pub fn item_layout(&self) -> Layout {
+        self.data.layout()
+    }
+
     #[inline(always)]
     pub fn get_drop_fn(&self) -> Option<unsafe fn(OwningPtr<'_>)> {
         self.data.drop_fn()
@@ -287,6 +335,27 @@ impl Column {
         }
     }
 
+    /// Swaps the values at `a` and `b` together with their ticks.
+    ///
+    /// # Safety
+    /// `a` and `b` must be distinct, initialized rows.
+    #[inline]
+    pub unsafe fn swap_nonoverlapping(&mut self, a: usize, b: usize) {
+        cfg::debug! {
+            assert!(a != b && a < self.capacity && b < self.capacity);
+        }
+
+        unsafe {
+            self.data.swap_nonoverlapping(a, b);
+            self.added_ticks.swap_nonoverlapping(a, b);
+            self.changed_ticks.swap_nonoverlapping(a, b);
+
+            cfg::debug! {
+                self.changed_by.as_mut().map(|cb| cb.swap_nonoverlapping(a, b));
+            }
+        }
+    }
+
     #[cfg_attr(not(any(debug_assertions, feature = "debug")), inline)]
     pub unsafe fn swap_remove_and_drop_nonoverlapping(&mut self, index: usize, last_index: usize) {
         cfg::debug! {
@@ -409,3 +478,46 @@ impl Column {
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::alloc::Layout;
+
+    use vc_ptr::OwningPtr;
+
+    use super::Column;
+    use crate::tick::Tick;
+    use crate::utils::DebugLocation;
+
+    #[test]
+    fn migrate_widens_values() {
+        let mut column = Column::with_capacity(Layout::new::<u32>(), None, 4);
+        for (index, value) in [1_u32, 20, u32::MAX].into_iter().enumerate() {
+            OwningPtr::make(value, |ptr| unsafe {
+                column.init_item(index, ptr, Tick::new(index as u32), DebugLocation::caller());
+            });
+        }
+
+        unsafe {
+            column.migrate(4, 3, Layout::new::<u64>(), None, |old, new| {
+                let value = u64::from(*old.as_ref::<u32>()) * 2;
+                new.as_ptr().cast::<u64>().write(value);
+            });
+        }
+
+        assert_eq!(column.item_layout(), Layout::new::<u64>());
+        for (index, value) in [2_u64, 40, u64::from(u32::MAX) * 2].into_iter().enumerate() {
+            unsafe {
+                assert_eq!(*column.get_data(index).as_ref::<u64>(), value);
+                assert_eq!(
+                    column.get_component_ticks(index).added,
+                    Tick::new(index as u32)
+                );
+            }
+        }
+        unsafe { column.dealloc(4, 3) };
+    }
+}
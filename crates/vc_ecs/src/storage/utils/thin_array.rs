@@ -131,6 +131,14 @@ impl<T> ThinArray<T> {
         }
     }
 
+    #[inline(always)]
+    pub const unsafe fn swap_nonoverlapping(&mut self, a: usize, b: usize) {
+        let base_ptr = self.data.as_ptr();
+        unsafe {
+            core::ptr::swap_nonoverlapping(base_ptr.add(a), base_ptr.add(b), 1);
+        }
+    }
+
     #[inline(always)]
     pub const unsafe fn copy_remove_nonoverlapping(&mut self, index: usize, last_index: usize) {
         let base_ptr = self.data.as_ptr();
@@ -7,8 +7,32 @@ pub const MAX_TICK_AGE: u32 = (u32::MAX / CHECK_CYCLE - 2) * CHECK_CYCLE - 1;
 // -----------------------------------------------------------------------------
 // Tick
 
+use core::cmp::Ordering;
+use core::ops::{Add, Sub};
+
 use vc_reflect::derive::Reflect;
 
+/// A change tick, a `u32` counter that wraps around on overflow.
+///
+/// `+` and `-` by a `u32` offset wrap as well.
+///
+/// # Ordering
+///
+/// [`Ord`] compares the raw values and is **not** aware of wraparound,
+/// so `a < b` says nothing about which tick happened first once the counter
+/// has wrapped. Use [`Tick::is_newer_than`] for change detection.
+///
+/// ```
+/// # use vc_ecs::tick::Tick;
+/// let last_run = Tick::new(u32::MAX - 1);
+/// let changed = last_run + 3; // wraps to 1
+/// let now = last_run + 5;
+///
+/// // The raw comparison claims the change happened before the last run...
+/// assert!(changed < last_run);
+/// // ...while the wrap-aware comparison gets it right.
+/// assert!(changed.is_newer_than(last_run, now));
+/// ```
 #[derive(Reflect, Debug, Copy, Clone, Default, Eq, PartialEq)]
 #[reflect(mini, default, debug, hash, partial_eq)]
 #[repr(transparent)]
@@ -74,6 +98,42 @@ impl core::hash::Hash for Tick {
     }
 }
 
+/// Raw comparison, see the [ordering caveat](Tick#ordering).
+impl PartialOrd for Tick {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Raw comparison, see the [ordering caveat](Tick#ordering).
+impl Ord for Tick {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.tick.cmp(&other.tick)
+    }
+}
+
+impl Add<u32> for Tick {
+    type Output = Self;
+
+    /// Wrapping addition.
+    #[inline(always)]
+    fn add(self, rhs: u32) -> Self {
+        Self::new(self.tick.wrapping_add(rhs))
+    }
+}
+
+impl Sub<u32> for Tick {
+    type Output = Self;
+
+    /// Wrapping subtraction.
+    #[inline(always)]
+    fn sub(self, rhs: u32) -> Self {
+        Self::new(self.tick.wrapping_sub(rhs))
+    }
+}
+
 // -----------------------------------------------------------------------------
 // CheckTicks
 
@@ -91,3 +151,31 @@ impl CheckTicks {
         self.0
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::Tick;
+
+    #[test]
+    fn wrapping_arithmetic() {
+        let max = Tick::new(u32::MAX);
+        assert_eq!((max + 1).get(), 0);
+        assert_eq!((max + 3).get(), 2);
+        assert_eq!((Tick::new(0) - 1).get(), u32::MAX);
+        assert_eq!(max + 7 - 7, max);
+    }
+
+    #[test]
+    fn ordering_is_raw() {
+        let before = Tick::new(u32::MAX);
+        let after = before + 2;
+        let now = before + 4;
+
+        assert!(after < before);
+        assert!(after.is_newer_than(before, now));
+        assert!(!before.is_newer_than(after, now));
+    }
+}
@@ -12,6 +12,17 @@ cfg::debug! {
 // -----------------------------------------------------------------------------
 // DebugLocation
 
+/// A value that only exists when the `debug` feature or `debug_assertions`
+/// is enabled, typically the source [`Location`](core::panic::Location)
+/// that spawned an entity or last changed a component or resource.
+///
+/// When disabled it is a zero-sized type and every operation is a no-op.
+/// When enabled, each tracked value stores one extra pointer, e.g. one per
+/// component per entity in table storage, and every change records the caller.
+///
+/// The choice is made at compile time only. To track change sources in a
+/// release build, enable the `debug` feature, which works without
+/// `debug_assertions`.
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DebugLocation<T: ?Sized = &'static Location<'static>>(
     #[cfg(any(debug_assertions, feature = "debug"))] T,
@@ -1,8 +1,137 @@
-use super::UnsafeWorldCell;
+use core::any::TypeId;
+
+use super::{UnsafeWorldCell, World};
+use crate::component::Mut;
+use crate::resource::Resource;
 
 // -----------------------------------------------------------------------------
 // DeferredWorld
 
+/// A [`World`] reference that can't make structural changes.
+///
+/// This is what [`ComponentHook`](crate::lifecycle::ComponentHook)s receive.
+/// A hook may run in the middle of a structural operation that still borrows
+/// entity storage, so it may only touch resources: it can read and mutate
+/// existing resources, but must not access components, spawn, despawn, insert
+/// or remove anything, nor insert or remove resources.
+///
+/// Resource access goes through the resource storage alone and never borrows
+/// the whole world.
 pub struct DeferredWorld<'w> {
-    _world: UnsafeWorldCell<'w>,
+    world: UnsafeWorldCell<'w>,
+}
+
+impl<'w> From<&'w mut World> for DeferredWorld<'w> {
+    #[inline]
+    fn from(world: &'w mut World) -> Self {
+        Self {
+            world: UnsafeWorldCell::new_mutable(world),
+        }
+    }
+}
+
+impl DeferredWorld<'_> {
+    /// Gets a reference to the resource of the given type,
+    /// or `None` if it does not exist.
+    #[inline]
+    pub fn get_resource<R: Resource>(&self) -> Option<&R> {
+        // SAFETY: only component registration metadata is read.
+        let world = unsafe { self.world.world_metadata() };
+        let id = world.components.get_resource_id(TypeId::of::<R>())?;
+        // SAFETY: `&self` ensures there are no mutable references to the
+        // resources through this handle.
+        let ptr = unsafe { self.world.resources() }.get(id)?.get_data()?;
+        // SAFETY: `id` was registered for `R`.
+        Some(unsafe { ptr.as_ref::<R>() })
+    }
+
+    /// Gets a mutable reference to the resource of the given type,
+    /// or `None` if it does not exist.
+    ///
+    /// Changes are tracked at the world's current change tick.
+    #[inline]
+    pub fn get_resource_mut<R: Resource>(&mut self) -> Option<Mut<'_, R>> {
+        // SAFETY: only component registration metadata and change ticks are read.
+        let world = unsafe { self.world.world_metadata() };
+        let id = world.components.get_resource_id(TypeId::of::<R>())?;
+        let last_run = world.last_change_tick;
+        let this_run = world.read_change_tick();
+        // SAFETY: `&mut self` ensures unique access to the resources through
+        // this handle, and a structural operation in progress never borrows them.
+        let resources = unsafe { self.world.resources_mut() };
+        let value = resources.get_mut(id)?.get_mut(last_run, this_run)?;
+        // SAFETY: `id` was registered for `R`.
+        Some(unsafe { value.with_type::<R>() })
+    }
+
+    /// Gets a mutable reference to the resource of the given type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist.
+    /// See [`get_resource_mut`](Self::get_resource_mut) for a non-panicking version.
+    #[inline]
+    #[track_caller]
+    pub fn resource_mut<R: Resource>(&mut self) -> Mut<'_, R> {
+        match self.get_resource_mut() {
+            Some(resource) => resource,
+            None => panic!(
+                "Requested resource {} does not exist.",
+                core::any::type_name::<R>()
+            ),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use super::DeferredWorld;
+    use crate::change_detection::DetectChanges;
+    use crate::component::ComponentId;
+    use crate::lifecycle::HookContext;
+    use crate::relationship::RelationshipHookMode;
+    use crate::resource::Resource;
+    use crate::utils::DebugLocation;
+    use crate::world::World;
+
+    struct Added(u32);
+    impl Resource for Added {}
+
+    fn count_added(mut world: DeferredWorld, _: HookContext) {
+        world.resource_mut::<Added>().0 += 1;
+    }
+
+    #[test]
+    fn hook_mutates_resource() {
+        let mut world = World::new();
+        world.insert_resource(Added(0));
+        world.last_change_tick = world.read_change_tick();
+        *world.change_tick.get_mut() += 1;
+
+        let entity = world.spawn_empty().id();
+        let context = HookContext {
+            entity,
+            component_id: ComponentId::new(NonZeroU32::new(1).unwrap()),
+            caller: DebugLocation::caller(),
+            relationship_hook_mode: RelationshipHookMode::Run,
+        };
+        count_added(DeferredWorld::from(&mut world), context);
+
+        let added = world.get_resource_mut::<Added>().unwrap();
+        assert_eq!(added.0, 1);
+        assert!(added.is_changed());
+        assert!(!added.is_added());
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn resource_mut_missing_panics() {
+        let mut world = World::new();
+        DeferredWorld::from(&mut world).resource_mut::<Added>();
+    }
 }
@@ -0,0 +1,137 @@
+#![expect(unsafe_code, reason = "read component data through UnsafeWorldCell")]
+
+use core::any::TypeId;
+use core::fmt;
+
+use vc_ptr::Ptr;
+
+use crate::archetype::Archetype;
+use crate::component::{Component, ComponentId};
+use crate::entity::{Entity, EntityLocation};
+use crate::storage::StorageType;
+use crate::world::UnsafeWorldCell;
+
+// -----------------------------------------------------------------------------
+// EntityRef
+
+/// A read-only reference to a particular [`Entity`] and all of its components.
+///
+/// This is the shared-access counterpart to [`EntityWorldMut`](super::EntityWorldMut),
+/// useful for inspecting or serializing a single entity without a fixed query.
+#[derive(Copy, Clone)]
+pub struct EntityRef<'w> {
+    world: UnsafeWorldCell<'w>,
+    entity: Entity,
+    location: EntityLocation,
+}
+
+impl fmt::Debug for EntityRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EntityRef")
+            .field("entity", &self.entity)
+            .field("location", &self.location)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'w> EntityRef<'w> {
+    /// # Safety
+    ///
+    /// - `location` must be the current location of `entity` in the world.
+    /// - `world` must allow read access to all components of `entity`
+    ///   for `'w`, and no mutable access to them may exist.
+    #[inline(always)]
+    pub(crate) unsafe fn new(
+        world: UnsafeWorldCell<'w>,
+        entity: Entity,
+        location: EntityLocation,
+    ) -> Self {
+        debug_assert_eq!(
+            unsafe { world.world_metadata() }
+                .entities
+                .get_location(entity),
+            Ok(Some(location))
+        );
+        Self {
+            world,
+            entity,
+            location,
+        }
+    }
+
+    /// Returns the [`Entity`] this handle refers to.
+    #[inline(always)]
+    pub fn id(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns the current location of the entity.
+    #[inline(always)]
+    pub fn location(&self) -> EntityLocation {
+        self.location
+    }
+
+    /// Returns the archetype the entity lives in.
+    #[inline]
+    pub fn archetype(&self) -> &'w Archetype {
+        // SAFETY: only archetype metadata is read.
+        let world = unsafe { self.world.world_metadata() };
+        &world.archetypes[self.location.archetype_id]
+    }
+
+    /// Returns the ids of all components of the entity.
+    #[inline]
+    pub fn components(&self) -> impl ExactSizeIterator<Item = ComponentId> + 'w {
+        self.archetype().components().iter().copied()
+    }
+
+    /// Returns `true` if the entity has a component with the given id.
+    #[inline]
+    pub fn contains_id(&self, id: ComponentId) -> bool {
+        self.archetype().contains(id)
+    }
+
+    /// Returns `true` if the entity has a component of type `C`.
+    #[inline]
+    pub fn contains<C: Component>(&self) -> bool {
+        // SAFETY: only component registration metadata is read.
+        let world = unsafe { self.world.world_metadata() };
+        world
+            .components
+            .get_component_id(TypeId::of::<C>())
+            .is_some_and(|id| self.contains_id(id))
+    }
+
+    /// Gets a type-erased pointer to the component with the given id,
+    /// or `None` if the entity does not have it.
+    pub fn get_by_id(&self, id: ComponentId) -> Option<Ptr<'w>> {
+        let index = self.archetype().get_storage_index(id)?;
+
+        // SAFETY for the storage accessors: the caller of `new` guarantees read
+        // access to the components of this entity, and only those are read.
+        match index.storage_type() {
+            StorageType::Table => unsafe {
+                // SAFETY: the archetype stores this component in its table at
+                // `raw_index`, and `table_row` is the row of this entity.
+                let table = self.world.tables().get(self.location.table_id);
+                Some(table.get_component(index.raw_index(), self.location.table_row))
+            },
+            StorageType::SparseSet => {
+                // SAFETY: the archetype stores this component in the sparse set at `raw_index`.
+                let sparse = unsafe { self.world.sparse_sets().get(index.raw_index()) };
+                sparse.get_component(self.entity.id())
+            }
+        }
+    }
+
+    /// Gets a reference to the component of type `C`,
+    /// or `None` if the entity does not have it.
+    #[inline]
+    pub fn get<C: Component>(&self) -> Option<&'w C> {
+        // SAFETY: only component registration metadata is read.
+        let world = unsafe { self.world.world_metadata() };
+        let id = world.components.get_component_id(TypeId::of::<C>())?;
+        // SAFETY: `id` was registered for `C`.
+        self.get_by_id(id).map(|ptr| unsafe { ptr.as_ref::<C>() })
+    }
+}
@@ -1 +1,11 @@
+// -----------------------------------------------------------------------------
+// Modules
 
+mod entity_ref;
+mod world_mut;
+
+// -----------------------------------------------------------------------------
+// Exports
+
+pub use entity_ref::EntityRef;
+pub use world_mut::EntityWorldMut;
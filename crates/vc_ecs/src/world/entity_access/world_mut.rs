@@ -0,0 +1,84 @@
+use core::fmt;
+
+use crate::archetype::Archetype;
+use crate::entity::{Entity, EntityLocation};
+use crate::world::World;
+
+// -----------------------------------------------------------------------------
+// EntityWorldMut
+
+/// A mutable reference to a particular [`Entity`], and the entire [`World`].
+///
+/// The handle keeps track of the entity's current location, so structural
+/// changes made through it do not require looking the entity up again.
+pub struct EntityWorldMut<'w> {
+    world: &'w mut World,
+    entity: Entity,
+    location: Option<EntityLocation>,
+}
+
+impl fmt::Debug for EntityWorldMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EntityWorldMut")
+            .field("entity", &self.entity)
+            .field("location", &self.location)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// # Safety
+    ///
+    /// `location` must be the current location of `entity` in `world`.
+    #[inline(always)]
+    pub(crate) unsafe fn new(
+        world: &'w mut World,
+        entity: Entity,
+        location: Option<EntityLocation>,
+    ) -> Self {
+        debug_assert_eq!(world.entities.get_location(entity), Ok(location));
+        Self {
+            world,
+            entity,
+            location,
+        }
+    }
+
+    /// Returns the [`Entity`] this handle refers to.
+    ///
+    /// The id stays the same across structural changes made through this handle.
+    #[inline(always)]
+    pub fn id(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns the current location of the entity,
+    /// or `None` if it is not spawned.
+    #[inline(always)]
+    pub fn location(&self) -> Option<EntityLocation> {
+        self.location
+    }
+
+    /// Returns the archetype the entity currently lives in,
+    /// or `None` if it is not spawned.
+    ///
+    /// Like [`location`](Self::location), this follows structural changes
+    /// made through this handle.
+    #[inline]
+    pub fn archetype(&self) -> Option<&Archetype> {
+        let location = self.location?;
+        Some(&self.world.archetypes[location.archetype_id])
+    }
+
+    /// Returns a shared reference to the underlying [`World`].
+    #[inline(always)]
+    pub fn world(&self) -> &World {
+        self.world
+    }
+
+    /// Consumes the handle, returning the underlying [`World`] reference.
+    #[inline(always)]
+    pub fn into_world_mut(self) -> &'w mut World {
+        self.world
+    }
+}
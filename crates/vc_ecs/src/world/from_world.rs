@@ -0,0 +1,24 @@
+use super::World;
+
+// -----------------------------------------------------------------------------
+// FromWorld
+
+/// Creates an instance of the type this trait is implemented for
+/// using data from the supplied [`World`].
+///
+/// This can be helpful for complex initialization or context-aware defaults,
+/// e.g. a resource that is built from another resource.
+///
+/// All types implementing [`Default`] implement this trait by calling
+/// [`Default::default`] and ignoring the world.
+pub trait FromWorld {
+    /// Creates `Self` using data from the given [`World`].
+    fn from_world(world: &mut World) -> Self;
+}
+
+impl<T: Default> FromWorld for T {
+    #[inline]
+    fn from_world(_world: &mut World) -> Self {
+        T::default()
+    }
+}
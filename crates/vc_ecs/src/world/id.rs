@@ -1,12 +1,22 @@
+use vc_os::sync::atomic::{AtomicU64, Ordering};
+
 // -----------------------------------------------------------------------------
 // WorldId
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub struct WorldId(u64);
 
+static NEXT_WORLD_ID: AtomicU64 = AtomicU64::new(0);
+
 impl WorldId {
     #[inline(always)]
     pub const fn new(id: u64) -> Self {
         Self(id)
     }
+
+    /// Returns an id that is unique among all worlds created by this program.
+    #[inline]
+    pub(crate) fn next() -> Self {
+        Self(NEXT_WORLD_ID.fetch_add(1, Ordering::Relaxed))
+    }
 }
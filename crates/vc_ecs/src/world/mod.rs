@@ -3,6 +3,7 @@
 
 mod deferred;
 mod entity_access;
+mod from_world;
 mod id;
 mod world;
 mod world_cell;
@@ -11,6 +12,8 @@ mod world_cell;
 // Exports
 
 pub use deferred::DeferredWorld;
+pub use entity_access::{EntityRef, EntityWorldMut};
+pub use from_world::FromWorld;
 pub use id::WorldId;
 pub use world::World;
 pub use world_cell::UnsafeWorldCell;
@@ -1,13 +1,20 @@
 use core::fmt;
 
-use vc_os::sync::atomic::AtomicU32;
+use vc_os::sync::atomic::{AtomicU32, Ordering};
+use vc_ptr::{OwningPtr, Ptr};
 
-use super::WorldId;
-use crate::archetype::Archetypes;
-use crate::component::{ComponentIdGenerator, Components};
-use crate::entity::{Entities, EntityAllocator};
-use crate::storage::Storages;
+use super::{EntityRef, EntityWorldMut, FromWorld, UnsafeWorldCell, WorldId};
+use crate::archetype::{ArchetypeId, Archetypes};
+use crate::cfg;
+use crate::component::{
+    Component, ComponentId, ComponentIdGenerator, ComponentTicksMut, Components,
+    ComponentsRegistrator, Mut, MutUntyped,
+};
+use crate::entity::{Entities, Entity, EntityAllocator, ReusePolicy};
+use crate::resource::Resource;
+use crate::storage::{BlobArray, NoSendResourceData, ResourceData, Storages, TableId, TableRow};
 use crate::tick::Tick;
+use crate::utils::{DebugCheckedUnwrap, DebugLocation};
 
 #[allow(unused, reason = "todo")]
 pub struct World {
@@ -39,3 +46,987 @@ impl fmt::Debug for World {
             .finish()
     }
 }
+
+impl Default for World {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Basic
+
+impl World {
+    /// Creates a new empty [`World`].
+    ///
+    /// Each world is assigned a unique [`WorldId`].
+    pub fn new() -> Self {
+        Self {
+            id: WorldId::next(),
+            archetypes: Archetypes::empty(),
+            storages: Storages::empty(),
+            entities: Entities::empty(),
+            allocator: EntityAllocator::new(),
+            components: Components::empty(),
+            generator: ComponentIdGenerator::new(),
+            // Start from `1` so that anything inserted before the first
+            // `last_change_tick` update is reported as added.
+            change_tick: AtomicU32::new(1),
+            last_check_tick: Tick::new(0),
+            last_change_tick: Tick::new(0),
+        }
+    }
+
+    /// Creates a new empty [`World`] with room for `capacity` entities,
+    /// reusing despawned entity ids according to `policy`.
+    pub fn with_entity_capacity(capacity: usize, policy: ReusePolicy) -> Self {
+        Self {
+            entities: Entities::with_capacity(capacity),
+            allocator: EntityAllocator::with_policy(policy),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the [`WorldId`] of this world.
+    #[inline(always)]
+    pub fn id(&self) -> WorldId {
+        self.id
+    }
+
+    /// Reads the current change tick of this world.
+    #[inline]
+    pub fn read_change_tick(&self) -> Tick {
+        Tick::new(self.change_tick.load(Ordering::Acquire))
+    }
+
+    /// Returns the change tick of the last time the world was updated.
+    #[inline(always)]
+    pub fn last_change_tick(&self) -> Tick {
+        self.last_change_tick
+    }
+
+    /// Returns the component and resource registrations of this world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_ecs::resource::Resource;
+    /// use vc_ecs::world::World;
+    ///
+    /// struct Score(u32);
+    /// impl Resource for Score {}
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(0));
+    ///
+    /// assert_eq!(world.components().iter_registered().count(), 1);
+    /// ```
+    #[inline(always)]
+    pub fn components(&self) -> &Components {
+        &self.components
+    }
+
+    /// Returns the archetypes of this world.
+    #[inline(always)]
+    pub fn archetypes(&self) -> &Archetypes {
+        &self.archetypes
+    }
+
+    /// Returns the component and resource storages of this world.
+    #[inline(always)]
+    pub fn storages(&self) -> &Storages {
+        &self.storages
+    }
+
+    /// Returns the entity location table of this world.
+    ///
+    /// Mutable access is not exposed, as editing it behind the world's back
+    /// would desynchronize it from the storages.
+    #[inline(always)]
+    pub fn entities(&self) -> &Entities {
+        &self.entities
+    }
+
+    /// Returns an [`UnsafeWorldCell`] that allows mutable access to this world.
+    #[inline]
+    pub fn as_unsafe_world_cell(&mut self) -> UnsafeWorldCell<'_> {
+        UnsafeWorldCell::new_mutable(self)
+    }
+
+    /// Returns a read-only [`UnsafeWorldCell`] for this world.
+    ///
+    /// Since `&self` rules out mutable references, reading through
+    /// [`UnsafeWorldCell::world_ref`] is sound for the lifetime of the cell.
+    /// With the `debug` cfg, any attempt to access the world mutably
+    /// through it panics.
+    #[inline]
+    pub fn as_unsafe_world_cell_readonly(&self) -> UnsafeWorldCell<'_> {
+        UnsafeWorldCell::new_readonly(self)
+    }
+
+    /// Returns the [`ComponentId`] of the component type `C`,
+    /// or `None` if it is not registered.
+    ///
+    /// This never registers `C`, and a registration that is still queued
+    /// is not visible until it is applied.
+    #[inline]
+    pub fn component_id<C: Component>(&self) -> Option<ComponentId> {
+        self.components.valid_component_id::<C>()
+    }
+
+    /// Returns the [`ComponentId`] of the component type `C`,
+    /// registering it if needed.
+    ///
+    /// An already registered type is looked up without going through
+    /// [`World::components_registrator`], which makes repeated calls cheap.
+    #[inline]
+    pub fn component_id_or_register<C: Component>(&mut self) -> ComponentId {
+        match self.component_id::<C>() {
+            Some(id) => id,
+            None => self.components_registrator().register_component::<C>(),
+        }
+    }
+
+    /// Returns the [`ComponentId`] of the resource type `R`,
+    /// or `None` if it is not registered.
+    ///
+    /// This never registers `R`, and a registration that is still queued
+    /// is not visible until it is applied.
+    #[inline]
+    pub fn resource_id<R: Resource>(&self) -> Option<ComponentId> {
+        self.components.valid_resource_id::<R>()
+    }
+
+    /// Returns a [`ComponentsRegistrator`] for this world,
+    /// with all queued registrations applied.
+    #[inline]
+    pub fn components_registrator(&mut self) -> ComponentsRegistrator<'_> {
+        // SAFETY: `components` and `generator` belong to the same world.
+        let mut registrator =
+            unsafe { ComponentsRegistrator::new(&mut self.components, &mut self.generator) };
+        registrator.apply_queued_registrations();
+        registrator
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn debug_check_component_id(&self, id: ComponentId) {
+        cfg::debug! {
+            assert!(
+                self.components.is_id_registered(id),
+                "{id:?} is not registered in this world, it may belong to another world",
+            );
+        }
+        let _ = id;
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Entities
+
+impl World {
+    /// Spawns an entity without any components, returning a handle to it.
+    ///
+    /// The entity is placed in the empty archetype and table.
+    #[track_caller]
+    pub fn spawn_empty(&mut self) -> EntityWorldMut<'_> {
+        let caller = DebugLocation::caller();
+        let entity = self.allocator.alloc();
+        // SAFETY: `entity` was just allocated, so it is not spawned.
+        unsafe { self.spawn_empty_at(entity, caller) }
+    }
+
+    /// Returns a read-only [`EntityRef`] for `entity`,
+    /// or `None` if it is not spawned.
+    #[inline]
+    pub fn entity(&self, entity: Entity) -> Option<EntityRef<'_>> {
+        let location = self.entities.get_location(entity).ok()??;
+        // SAFETY: `location` is current, and `&self` grants read access to the whole world.
+        Some(unsafe { EntityRef::new(self.as_unsafe_world_cell_readonly(), entity, location) })
+    }
+
+    /// Swaps the rows `a` and `b` of the table `table_id`, moving every
+    /// component value, its ticks and the stored entity.
+    ///
+    /// The [`EntityLocation`](crate::entity::EntityLocation) and archetype table row of both entities are
+    /// updated, so [`Entities::get_location`] keeps resolving them. This allows
+    /// sorting table storage in place.
+    ///
+    /// [`Entities::get_location`]: crate::entity::Entities::get_location
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table_id` does not exist, or if either row is out of bounds.
+    pub fn swap_table_rows(&mut self, table_id: TableId, a: TableRow, b: TableRow) {
+        let table = &mut self.storages.tables[table_id];
+        table.swap_rows(a, b);
+
+        for table_row in [a, b] {
+            let entity = table.entities()[table_row.index()];
+            let mut location = self
+                .entities
+                .get_location_spawned(entity)
+                .expect("entities stored in a table are spawned");
+            location.table_row = table_row;
+            self.archetypes[location.archetype_id]
+                .set_entity_table_row(location.archetype_row, table_row);
+            self.entities.update_location(entity, location);
+        }
+    }
+
+    /// Despawns every entity at once, keeping the storage capacity for reuse.
+    ///
+    /// This is a fast path for benchmarks and teardown, the cost is linear in
+    /// the number of component values rather than in per-entity bookkeeping.
+    ///
+    /// # Caveats
+    ///
+    /// - **No lifecycle hooks or observers run.** Component values are still
+    ///   dropped, but `on_replace`, `on_remove` and `on_despawn` are skipped.
+    /// - The entity allocator is restarted, so handles obtained before the
+    ///   reset may compare equal to entities spawned after it. Discard them.
+    ///
+    /// Resources, components and archetypes stay registered.
+    pub fn reset_entities_fast(&mut self) {
+        // `Tables::clear_entities` and `SparseSets::clear_entities` free the
+        // memory, so clear each storage individually instead.
+        for (_, table) in self.storages.tables.iter_mut() {
+            table.clear_entities();
+        }
+        for (_, sparse_set) in self.storages.sparse_sets.iter_mut() {
+            sparse_set.clear_entities();
+        }
+        for archetype in self.archetypes.iter_mut() {
+            archetype.clear_entities();
+        }
+        self.entities.clear();
+        self.allocator.restart();
+    }
+
+    /// # Safety
+    ///
+    /// `entity` must be a valid entity that is not spawned.
+    unsafe fn spawn_empty_at(
+        &mut self,
+        entity: Entity,
+        caller: DebugLocation,
+    ) -> EntityWorldMut<'_> {
+        debug_assert!(self.entities.check_spawnable(entity).is_ok());
+
+        // SAFETY: the empty table always exists.
+        let table = unsafe { self.storages.tables.get_mut(TableId::EMPTY) };
+        let table_row = unsafe { table.allocate(entity) };
+        let archetype = &mut self.archetypes[ArchetypeId::EMPTY];
+        let location = unsafe { archetype.allocate(entity, table_row) };
+
+        let change_tick = self.read_change_tick();
+        self.entities.set_location(entity.id(), Some(location));
+        self.entities
+            .set_spawned_or_despawned(entity.id(), caller, change_tick);
+
+        // SAFETY: `location` was just assigned to `entity`.
+        unsafe { EntityWorldMut::new(self, entity, Some(location)) }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Resources
+
+impl World {
+    /// Registers a [`Resource`] type, returning its [`ComponentId`].
+    ///
+    /// This does not insert a value; see [`World::insert_resource`].
+    #[inline]
+    pub fn register_resource<R: Resource>(&mut self) -> ComponentId {
+        self.components_registrator().register_resource::<R>()
+    }
+
+    /// Inserts a new resource with the given `value`,
+    /// overwriting (and dropping) any previous value of the same type.
+    #[track_caller]
+    pub fn insert_resource<R: Resource>(&mut self, value: R) {
+        let caller = DebugLocation::caller();
+        let id = self.register_resource::<R>();
+        OwningPtr::make(value, |ptr| {
+            // SAFETY: `id` was just registered for `R`, and `ptr` points to an `R`.
+            unsafe { self.insert_resource_by_id(id, ptr, caller) }
+        });
+    }
+
+    /// Inserts a type-erased resource value for the given [`ComponentId`].
+    ///
+    /// # Safety
+    ///
+    /// - `id` must be a resource registered in this world.
+    /// - `value` must point to a valid value of the resource's type.
+    #[inline]
+    pub unsafe fn insert_resource_by_id(
+        &mut self,
+        id: ComponentId,
+        value: OwningPtr<'_>,
+        caller: DebugLocation,
+    ) {
+        self.debug_check_component_id(id);
+        let change_tick = self.read_change_tick();
+        let data = self
+            .storages
+            .resources
+            .get_data_or_insert(id, &self.components);
+        unsafe {
+            data.insert(value, change_tick, caller);
+        }
+    }
+
+    /// Returns `true` if a resource of type `R` exists.
+    #[inline]
+    pub fn contains_resource<R: Resource>(&self) -> bool {
+        self.resource_id::<R>()
+            .and_then(|id| self.storages.resources.get(id))
+            .is_some_and(|data| data.is_present())
+    }
+
+    /// Gets a reference to the resource of the given type,
+    /// or `None` if it does not exist.
+    #[inline]
+    pub fn get_resource<R: Resource>(&self) -> Option<&R> {
+        let id = self.resource_id::<R>()?;
+        let ptr = self.storages.resources.get(id)?.get_data()?;
+        // SAFETY: `id` was registered for `R`.
+        Some(unsafe { ptr.as_ref::<R>() })
+    }
+
+    /// Gets a type-erased pointer to the resource with the given [`ComponentId`],
+    /// or `None` if it does not exist.
+    ///
+    /// # Panics
+    ///
+    /// With the `debug` cfg, panics if `id` is not registered in this world,
+    /// e.g. because it was taken from another world.
+    #[inline]
+    #[track_caller]
+    pub fn get_resource_by_id(&self, id: ComponentId) -> Option<Ptr<'_>> {
+        self.debug_check_component_id(id);
+        self.storages.resources.get(id)?.get_data()
+    }
+
+    /// Gets a mutable reference to the resource of the given type,
+    /// or `None` if it does not exist.
+    #[inline]
+    pub fn get_resource_mut<R: Resource>(&mut self) -> Option<Mut<'_, R>> {
+        let id = self.resource_id::<R>()?;
+        let last_run = self.last_change_tick;
+        let this_run = self.read_change_tick();
+        let value = self
+            .storages
+            .resources
+            .get_mut(id)?
+            .get_mut(last_run, this_run)?;
+        // SAFETY: `id` was registered for `R`.
+        Some(unsafe { value.with_type::<R>() })
+    }
+
+    /// Gets a mutable reference to the resource of type `R`,
+    /// initializing it with [`FromWorld::from_world`] if it does not exist.
+    ///
+    /// A freshly initialized resource is reported as added in this run.
+    #[track_caller]
+    pub fn get_resource_or_init<R: Resource + FromWorld>(&mut self) -> Mut<'_, R> {
+        let caller = DebugLocation::caller();
+        let id = self.register_resource::<R>();
+
+        if !self
+            .storages
+            .resources
+            .get(id)
+            .is_some_and(|data| data.is_present())
+        {
+            // `from_world` may access the world freely, so the value must be
+            // fully inserted before any borrow of the resource storage is created.
+            let value = R::from_world(self);
+            OwningPtr::make(value, |ptr| {
+                // SAFETY: `id` was registered for `R`, and `ptr` points to an `R`.
+                unsafe { self.insert_resource_by_id(id, ptr, caller) }
+            });
+        }
+
+        let last_run = self.last_change_tick;
+        let this_run = self.read_change_tick();
+        let data = self.storages.resources.get_mut(id);
+        // SAFETY: the resource was inserted above, and `id` was registered for `R`.
+        unsafe {
+            data.and_then(|data| data.get_mut(last_run, this_run))
+                .debug_checked_unwrap()
+                .with_type::<R>()
+        }
+    }
+
+    /// Temporarily removes the resource `R` from the world, runs `f` with
+    /// the world and the resource, then puts the resource back.
+    ///
+    /// The resource keeps its change ticks across the removal, and changes made
+    /// through the provided [`Mut`] are tracked as usual.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` does not exist, or if `f` inserts another `R`.
+    /// See [`World::try_resource_scope`] for a non-panicking version.
+    #[track_caller]
+    pub fn resource_scope<R: Resource, U>(&mut self, f: impl FnOnce(&mut World, Mut<R>) -> U) -> U {
+        match self.try_resource_scope(f) {
+            Some(result) => result,
+            None => panic!(
+                "Requested resource {} does not exist.",
+                core::any::type_name::<R>()
+            ),
+        }
+    }
+
+    /// Like [`World::resource_scope`], but returns `None` without calling `f`
+    /// if the resource `R` does not exist.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` inserts another `R`, because the original resource is
+    /// reinserted after `f` returns.
+    pub fn try_resource_scope<R: Resource, U>(
+        &mut self,
+        f: impl FnOnce(&mut World, Mut<R>) -> U,
+    ) -> Option<U> {
+        let last_run = self.last_change_tick;
+        let this_run = self.read_change_tick();
+        let id = self.resource_id::<R>()?;
+        let (ptr, mut ticks, mut caller) = self.storages.resources.get_mut(id)?.remove()?;
+
+        // Move the value onto the stack, so `f` can access the world freely.
+        // SAFETY: `id` was registered for `R`.
+        let mut value = unsafe { ptr.read::<R>() };
+        let value_mut = Mut::new(
+            &mut value,
+            &mut ticks.added,
+            &mut ticks.changed,
+            last_run,
+            this_run,
+            caller.as_mut(),
+        );
+
+        let result = f(self, value_mut);
+
+        assert!(
+            !self.contains_resource::<R>(),
+            "Resource {} was inserted during a call to `World::resource_scope`, \
+            this is not allowed as the original resource is reinserted after the closure returns.",
+            core::any::type_name::<R>(),
+        );
+
+        OwningPtr::make(value, |ptr| {
+            // SAFETY: `id` was registered for `R`, and `ptr` points to an `R`.
+            unsafe {
+                self.storages
+                    .resources
+                    .get_mut(id)
+                    .debug_checked_unwrap()
+                    .insert_with_ticks(ptr, ticks, caller);
+            }
+        });
+
+        Some(result)
+    }
+
+    /// Like [`World::try_resource_scope`], but for the resource with the given
+    /// [`ComponentId`], which is handed to `f` as a [`MutUntyped`].
+    ///
+    /// Returns `None` without calling `f` if the resource does not exist.
+    /// Non-send resources are supported, but only on the thread they belong to.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `f` inserts another value for the resource.
+    /// - Panics if the resource is non-send and this is not its thread.
+    /// - With the `debug` cfg, panics if `id` is not registered in this world.
+    #[track_caller]
+    pub fn resource_scope_by_id<U>(
+        &mut self,
+        id: ComponentId,
+        f: impl FnOnce(&mut World, MutUntyped<'_>) -> U,
+    ) -> Option<U> {
+        /// Owns the value while it is out of the storage, dropping it on unwind.
+        struct ScopedValue {
+            data: BlobArray,
+            is_present: bool,
+        }
+
+        impl Drop for ScopedValue {
+            fn drop(&mut self) {
+                // SAFETY: the capacity is 1, and the item is initialized if `is_present`.
+                unsafe { self.data.dealloc(1, self.is_present as usize) };
+            }
+        }
+
+        self.debug_check_component_id(id);
+        let last_run = self.last_change_tick;
+        let this_run = self.read_change_tick();
+        let info = self.components.get_info(id)?;
+        let (layout, is_send) = (info.layout(), info.is_send_and_sync());
+
+        let (ptr, mut ticks, mut caller) = if is_send {
+            self.storages.resources.get_mut(id)?.remove()?
+        } else {
+            self.storages.non_send_resources.get_mut(id)?.remove()?
+        };
+        // Move the value into its own allocation, so `f` can access the world freely.
+        // SAFETY: the buffer has the layout and drop function of the resource.
+        let mut value = ScopedValue {
+            data: unsafe { BlobArray::with_capacity(layout, info.drop_fn(), 1) },
+            is_present: false,
+        };
+        // SAFETY: `ptr` points to a value of the resource's layout.
+        unsafe { value.data.init_item(0, ptr) };
+        value.is_present = true;
+
+        let value_mut = MutUntyped::new(
+            // SAFETY: the item was just initialized.
+            unsafe { value.data.get_item_mut(0) },
+            ComponentTicksMut {
+                added: &mut ticks.added,
+                changed: &mut ticks.changed,
+                changed_by: caller.as_mut(),
+                last_run,
+                this_run,
+            },
+            layout,
+        );
+
+        let result = f(self, value_mut);
+
+        let reinserted = if is_send {
+            self.storages
+                .resources
+                .get(id)
+                .map(ResourceData::is_present)
+        } else {
+            self.storages
+                .non_send_resources
+                .get(id)
+                .map(NoSendResourceData::is_present)
+        };
+        assert!(
+            reinserted != Some(true),
+            "Resource {} was inserted during a call to `World::resource_scope_by_id`, \
+            this is not allowed as the original resource is reinserted after the closure returns.",
+            self.components.get_debug_name(id),
+        );
+
+        value.is_present = false;
+        // SAFETY: the item is initialized, and `is_present` is cleared so it is not dropped twice.
+        let ptr = unsafe { value.data.remove_last(0) };
+        // SAFETY: `id` is a resource with the layout of `ptr`.
+        unsafe {
+            if is_send {
+                self.storages
+                    .resources
+                    .get_mut(id)
+                    .debug_checked_unwrap()
+                    .insert_with_ticks(ptr, ticks, caller);
+            } else {
+                self.storages
+                    .non_send_resources
+                    .get_mut(id)
+                    .debug_checked_unwrap()
+                    .insert_with_ticks(ptr, ticks, caller);
+            }
+        }
+
+        Some(result)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use core::num::NonZeroU32;
+
+    use vc_ptr::OwningPtr;
+
+    use super::World;
+    use crate::archetype::ArchetypeId;
+    use crate::change_detection::DetectChanges;
+    use crate::component::{
+        Component, ComponentDescriptor, ComponentId, ComponentsRegistrator, Mut, Mutable,
+    };
+    use crate::entity::{Entity, EntityId};
+    use crate::resource::Resource;
+    use crate::storage::{StorageType, TableId};
+    use crate::utils::DebugLocation;
+    use crate::world::FromWorld;
+
+    #[derive(Default)]
+    struct Counter(u32);
+    impl Resource for Counter {}
+
+    struct Registry(Vec<String>);
+    impl Resource for Registry {}
+
+    struct RegistrySize(usize);
+    impl Resource for RegistrySize {}
+
+    impl FromWorld for RegistrySize {
+        fn from_world(world: &mut World) -> Self {
+            Self(world.get_resource::<Registry>().map_or(0, |r| r.0.len()))
+        }
+    }
+
+    #[test]
+    fn reset_entities_fast_empties_world() {
+        let mut world = World::new();
+        world.insert_resource(Counter(1));
+        let before: Vec<Entity> = (0..1000).map(|_| world.spawn_empty().id()).collect();
+        let capacity = world.storages.tables[TableId::EMPTY].capacity();
+
+        world.reset_entities_fast();
+
+        assert!(!world.entities.any_spawned());
+        assert!(
+            before
+                .iter()
+                .all(|&entity| !world.entities.is_alive(entity))
+        );
+        assert_eq!(world.storages.tables[TableId::EMPTY].entity_count(), 0);
+        assert_eq!(world.archetypes[ArchetypeId::EMPTY].entity_count(), 0);
+        assert_eq!(world.get_resource::<Counter>().unwrap().0, 1);
+
+        // Storage is kept for reuse.
+        assert_eq!(world.storages.tables[TableId::EMPTY].capacity(), capacity);
+        let entity = world.spawn_empty().id();
+        assert!(world.entities.is_alive(entity));
+        assert_eq!(world.storages.tables[TableId::EMPTY].capacity(), capacity);
+    }
+
+    #[test]
+    fn get_resource_by_id() {
+        let mut world = World::new();
+        world.insert_resource(Counter(7));
+        let id = world.components.resource_id_of::<Counter>().unwrap();
+
+        let ptr = world.get_resource_by_id(id).unwrap();
+        assert_eq!(unsafe { ptr.as_ref::<Counter>() }.0, 7);
+    }
+
+    #[test]
+    #[cfg(any(feature = "debug", debug_assertions))]
+    #[should_panic(expected = "ComponentId(100) is not registered in this world")]
+    fn get_resource_by_foreign_id() {
+        let world = World::new();
+        let id = ComponentId::new(NonZeroU32::new(100).unwrap());
+        world.get_resource_by_id(id);
+    }
+
+    #[test]
+    fn spawn_empty() {
+        let mut world = World::new();
+
+        let first = world.spawn_empty();
+        let id = first.id();
+        let location = first.location().unwrap();
+        assert_eq!(location.archetype_id, ArchetypeId::EMPTY);
+        assert_eq!(location.table_id, TableId::EMPTY);
+
+        let second = world.spawn_empty().id();
+        assert_ne!(id, second);
+        assert!(world.entities.is_alive(id));
+        assert!(world.entities.is_alive(second));
+        assert_eq!(world.entities.get_location_spawned(id), Ok(location));
+        assert_eq!(world.archetypes[ArchetypeId::EMPTY].len(), 2);
+    }
+
+    #[test]
+    fn typed_id_lookup_does_not_register() {
+        struct Foo;
+        impl Component for Foo {
+            const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+            type Mutability = Mutable;
+        }
+
+        let mut world = World::new();
+        assert_eq!(world.component_id::<Foo>(), None);
+        assert_eq!(world.resource_id::<Counter>(), None);
+
+        let id = world.components_registrator().register_component::<Foo>();
+        assert_eq!(world.component_id::<Foo>(), Some(id));
+
+        let id = world.register_resource::<Counter>();
+        assert_eq!(world.resource_id::<Counter>(), Some(id));
+        assert_eq!(
+            world.component_id::<Foo>(),
+            world.components().component_id_of::<Foo>()
+        );
+    }
+
+    #[test]
+    fn component_id_or_register_is_cached() {
+        struct Foo;
+        impl Component for Foo {
+            const STORAGE_TYPE: StorageType = StorageType::Table;
+            type Mutability = Mutable;
+        }
+
+        let mut world = World::new();
+        let id = world.component_id_or_register::<Foo>();
+        assert_eq!(world.component_id::<Foo>(), Some(id));
+
+        let next = world.generator.peek();
+        assert_eq!(world.component_id_or_register::<Foo>(), id);
+        assert_eq!(world.generator.peek(), next);
+    }
+
+    #[test]
+    fn queued_registration_is_not_visible() {
+        struct Queued;
+        impl Component for Queued {
+            const STORAGE_TYPE: StorageType = StorageType::Table;
+            type Mutability = Mutable;
+        }
+
+        let mut world = World::new();
+        // SAFETY: `components` and `generator` belong to the same world.
+        let registrator =
+            unsafe { ComponentsRegistrator::new(&mut world.components, &mut world.generator) };
+        let queued = registrator.as_queued();
+        let component = queued.queue_register_component::<Queued>();
+        let resource = queued.queue_register_resource::<Counter>();
+
+        assert_eq!(world.component_id::<Queued>(), None);
+        assert_eq!(world.resource_id::<Counter>(), None);
+
+        world.components_registrator();
+        assert_eq!(world.component_id::<Queued>(), Some(component));
+        assert_eq!(world.resource_id::<Counter>(), Some(resource));
+    }
+
+    #[test]
+    fn readonly_cell_reads_world() {
+        let mut world = World::new();
+        world.insert_resource(Counter(3));
+        world.insert_resource(Registry(alloc::vec!["a".into()]));
+        let entity = world.spawn_empty().id();
+
+        let world = &world;
+        let cell = world.as_unsafe_world_cell_readonly();
+        // SAFETY: the cell was created from `&World`.
+        let read = unsafe { cell.world_ref() };
+        assert_eq!(read.get_resource::<Counter>().unwrap().0, 3);
+        assert_eq!(read.get_resource::<Registry>().unwrap().0.len(), 1);
+        assert_eq!(read.entity(entity).unwrap().components().len(), 0);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    #[should_panic(expected = "as_unsafe_world_cell_readonly")]
+    fn readonly_cell_rejects_mut() {
+        let world = World::new();
+        let cell = world.as_unsafe_world_cell_readonly();
+        let _ = unsafe { cell.world_mut() };
+    }
+
+    #[test]
+    fn entity_ref_reads_location() {
+        struct Marker;
+        impl Component for Marker {
+            const STORAGE_TYPE: StorageType = StorageType::Table;
+            type Mutability = Mutable;
+        }
+
+        let mut world = World::new();
+        let marker = world
+            .components_registrator()
+            .register_component::<Marker>();
+        let entity = world.spawn_empty().id();
+
+        let entity_ref = world.entity(entity).unwrap();
+        assert_eq!(entity_ref.id(), entity);
+        assert_eq!(entity_ref.location().archetype_id, ArchetypeId::EMPTY);
+        assert_eq!(entity_ref.components().len(), 0);
+        assert!(!entity_ref.contains::<Marker>());
+        assert!(!entity_ref.contains_id(marker));
+        assert!(entity_ref.get::<Marker>().is_none());
+
+        let missing = Entity::from_id(EntityId::new(NonZeroU32::new(100).unwrap()));
+        assert!(world.entity(missing).is_none());
+    }
+
+    #[test]
+    fn swap_table_rows_updates_locations() {
+        let mut world = World::new();
+        let spawned: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+        let first = world.entities.get_location_spawned(spawned[0]).unwrap();
+        let last = world.entities.get_location_spawned(spawned[2]).unwrap();
+
+        world.swap_table_rows(TableId::EMPTY, first.table_row, last.table_row);
+
+        let table = &world.storages.tables[TableId::EMPTY];
+        assert_eq!(table.entities(), [spawned[2], spawned[1], spawned[0]]);
+        for (&entity, expected) in [spawned[0], spawned[2]].iter().zip([last, first]) {
+            let location = world.entities.get_location(entity).unwrap().unwrap();
+            assert_eq!(location.table_row, expected.table_row);
+            assert_eq!(table.entities()[location.table_row.index()], entity);
+            let archetype = &world.archetypes[location.archetype_id];
+            assert_eq!(
+                archetype.entity_table_row(location.archetype_row),
+                location.table_row
+            );
+        }
+    }
+
+    #[test]
+    fn spawn_empty_uses_empty_archetype() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+
+        let location = world.entity(entity).unwrap().location();
+        assert_eq!(location.archetype_id, ArchetypeId::EMPTY);
+        assert_eq!(location.table_id, TableId::EMPTY);
+
+        let entity_mut = world.spawn_empty();
+        let archetype = entity_mut.archetype().unwrap();
+        assert_eq!(archetype.id(), ArchetypeId::EMPTY);
+        assert!(archetype.components().is_empty());
+        let other = entity_mut.id();
+        assert_eq!(
+            world.entity(other).unwrap().archetype().id(),
+            ArchetypeId::EMPTY
+        );
+
+        let empty = world.archetypes().empty_archetype();
+        assert!(empty.components().is_empty());
+        assert_eq!(empty.table_id(), TableId::EMPTY);
+        assert_eq!(empty.len(), 2);
+    }
+
+    #[test]
+    fn insert_and_get_resource() {
+        let mut world = World::new();
+        assert!(!world.contains_resource::<Counter>());
+        assert!(world.get_resource::<Counter>().is_none());
+
+        world.insert_resource(Counter(3));
+        assert!(world.contains_resource::<Counter>());
+        assert_eq!(world.get_resource::<Counter>().unwrap().0, 3);
+
+        world.get_resource_mut::<Counter>().unwrap().0 += 1;
+        assert_eq!(world.get_resource::<Counter>().unwrap().0, 4);
+    }
+
+    #[test]
+    fn get_resource_or_init_from_world() {
+        let mut world = World::new();
+        world.insert_resource(Registry(alloc::vec!["a".into(), "b".into()]));
+
+        let size = world.get_resource_or_init::<RegistrySize>();
+        assert!(size.is_added());
+        assert_eq!(size.0, 2);
+
+        // An existing resource is returned unchanged.
+        world.insert_resource(Registry(Vec::new()));
+        assert_eq!(world.get_resource_or_init::<RegistrySize>().0, 2);
+    }
+
+    #[test]
+    fn resource_ticks_without_borrow() {
+        let mut world = World::new();
+        let id = world.register_resource::<Counter>();
+        assert!(world.storages().resources.get_ticks(id).is_none());
+
+        world.insert_resource(Counter(1));
+        let ticks = world.storages().resources.get_ticks(id).unwrap();
+        assert_eq!(ticks.added.get(), 1);
+        assert_eq!(ticks.changed.get(), 1);
+
+        *world.change_tick.get_mut() = 4;
+        world.get_resource_mut::<Counter>().unwrap().0 += 1;
+        let ticks = world.storages().resources.get_ticks(id).unwrap();
+        assert_eq!(ticks.added.get(), 1);
+        assert_eq!(ticks.changed.get(), 4);
+    }
+
+    #[test]
+    fn try_resource_scope_preserves_ticks() {
+        let mut world = World::new();
+        world.insert_resource(Counter(1));
+        world.insert_resource(Registry(Vec::new()));
+
+        *world.change_tick.get_mut() = 5;
+        let result = world.try_resource_scope(|world, mut counter: Mut<Counter>| {
+            assert!(!world.contains_resource::<Counter>());
+            world
+                .get_resource_mut::<Registry>()
+                .unwrap()
+                .0
+                .push("scoped".into());
+            counter.0 += 1;
+            counter.0
+        });
+        assert_eq!(result, Some(2));
+
+        let counter = world.get_resource_mut::<Counter>().unwrap();
+        assert_eq!(counter.0, 2);
+        assert_eq!(counter.added_tick().get(), 1);
+        assert_eq!(counter.changed_tick().get(), 5);
+        assert_eq!(world.get_resource::<Registry>().unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn try_resource_scope_missing() {
+        let mut world = World::new();
+        let result = world.try_resource_scope(|_, _: Mut<Counter>| {
+            panic!("closure called for a missing resource")
+        });
+        assert!(result.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn resource_scope_missing_panics() {
+        let mut world = World::new();
+        world.resource_scope(|_, _: Mut<Counter>| {});
+    }
+
+    #[test]
+    fn resource_scope_by_id_dynamic() {
+        let mut world = World::new();
+        let id = world
+            .components_registrator()
+            .register_dynamic(ComponentDescriptor::new_resource::<Registry>());
+        assert!(world.resource_scope_by_id(id, |_, _| ()).is_none());
+
+        OwningPtr::make(Registry(Vec::new()), |ptr| unsafe {
+            world.insert_resource_by_id(id, ptr, DebugLocation::caller());
+        });
+
+        *world.change_tick.get_mut() = 5;
+        let len = world.resource_scope_by_id(id, |world, value| {
+            assert!(world.get_resource_by_id(id).is_none());
+            let mut registry = unsafe { value.downcast_mut::<Registry>() };
+            registry.0.push("scoped".into());
+            registry.0.len()
+        });
+        assert_eq!(len, Some(1));
+
+        let registry = unsafe { world.get_resource_by_id(id).unwrap().as_ref::<Registry>() };
+        assert_eq!(registry.0, ["scoped"]);
+        let ticks = world.storages.resources.get_ticks(id).unwrap();
+        assert_eq!(ticks.added.get(), 1);
+        assert_eq!(ticks.changed.get(), 5);
+    }
+
+    #[test]
+    fn get_resource_or_init_default() {
+        let mut world = World::new();
+        world.get_resource_or_init::<Counter>().0 += 5;
+        assert_eq!(world.get_resource::<Counter>().unwrap().0, 5);
+    }
+}
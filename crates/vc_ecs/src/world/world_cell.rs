@@ -6,10 +6,20 @@ use core::marker::PhantomData;
 use core::ptr;
 
 use super::World;
+use crate::storage::{Resources, SparseSets, Tables};
 
 // -----------------------------------------------------------------------------
 // UnsafeWorldCell
 
+/// A [`World`] reference with manually checked access.
+///
+/// The cell itself only records where the world is; callers of its unsafe
+/// accessors are responsible for not creating conflicting borrows.
+///
+/// A cell created from `&World` (see [`World::as_unsafe_world_cell_readonly`])
+/// is read-only: with the `debug` cfg, [`world_mut`](Self::world_mut) panics
+/// on it, and reading through [`world_ref`](Self::world_ref) is sound as long
+/// as the cell does not outlive the shared borrow it was created from.
 #[derive(Copy, Clone)]
 pub struct UnsafeWorldCell<'w> {
     _marker: PhantomData<(&'w World, &'w UnsafeCell<World>)>,
@@ -42,6 +52,7 @@ impl<'w> From<&'w World> for UnsafeWorldCell<'w> {
 }
 
 impl<'w> UnsafeWorldCell<'w> {
+    /// Creates a read-only cell from a shared world reference.
     #[inline(always)]
     pub const fn new_readonly(world: &'w World) -> Self {
         Self {
@@ -52,6 +63,7 @@ impl<'w> UnsafeWorldCell<'w> {
         }
     }
 
+    /// Creates a cell from a mutable world reference, allowing mutable access.
     #[inline(always)]
     pub const fn new_mutable(world: &'w mut World) -> Self {
         Self {
@@ -62,6 +74,7 @@ impl<'w> UnsafeWorldCell<'w> {
         }
     }
 
+    /// With the `debug` cfg, panics if this cell was created read-only.
     #[inline(always)]
     #[cfg_attr(any(debug_assertions, feature = "debug"), track_caller)]
     pub const fn assert_allows_mutable_access(self) {
@@ -72,19 +85,86 @@ impl<'w> UnsafeWorldCell<'w> {
         );
     }
 
+    /// Returns a mutable reference to the whole world.
+    ///
+    /// # Safety
+    ///
+    /// The cell must allow mutable access, and no other reference into the
+    /// world may be alive while the returned one is used.
     #[inline(always)]
+    #[cfg_attr(any(debug_assertions, feature = "debug"), track_caller)]
     pub const unsafe fn world_mut(self) -> &'w mut World {
         self.assert_allows_mutable_access();
         unsafe { &mut *self.ptr }
     }
 
+    /// Returns a shared reference to the whole world.
+    ///
+    /// # Safety
+    ///
+    /// No mutable reference into the world may be alive while the returned one is used.
+    /// This always holds for a read-only cell.
     #[inline(always)]
     pub const unsafe fn world_ref(self) -> &'w World {
         unsafe { &*self.ptr }
     }
 
+    /// Returns a shared reference to the world for reading metadata,
+    /// such as registered components, archetypes and entity locations.
+    ///
+    /// # Safety
+    ///
+    /// The returned reference must not be used to read component or resource values.
     #[inline(always)]
     pub const unsafe fn world_metadata(self) -> &'w World {
         unsafe { &*self.ptr }
     }
+
+    /// Returns the world's table storage, without borrowing the rest of the world.
+    ///
+    /// # Safety
+    ///
+    /// No mutable reference to the [`Tables`] may be alive while the returned
+    /// one is used, and component values may only be read where the caller
+    /// has read access to them.
+    #[inline(always)]
+    pub const unsafe fn tables(self) -> &'w Tables {
+        unsafe { &(*self.ptr).storages.tables }
+    }
+
+    /// Returns the world's sparse-set storage, without borrowing the rest of the world.
+    ///
+    /// # Safety
+    ///
+    /// No mutable reference to the [`SparseSets`] may be alive while the returned
+    /// one is used, and component values may only be read where the caller
+    /// has read access to them.
+    #[inline(always)]
+    pub const unsafe fn sparse_sets(self) -> &'w SparseSets {
+        unsafe { &(*self.ptr).storages.sparse_sets }
+    }
+
+    /// Returns the world's resource storage, without borrowing the rest of the world.
+    ///
+    /// # Safety
+    ///
+    /// No mutable reference to the [`Resources`] may be alive while the returned
+    /// one is used.
+    #[inline(always)]
+    pub const unsafe fn resources(self) -> &'w Resources {
+        unsafe { &(*self.ptr).storages.resources }
+    }
+
+    /// Returns the world's resource storage mutably, without borrowing the rest of the world.
+    ///
+    /// # Safety
+    ///
+    /// The cell must allow mutable access, and no other reference to the
+    /// [`Resources`] may be alive while the returned one is used.
+    #[inline(always)]
+    #[cfg_attr(any(debug_assertions, feature = "debug"), track_caller)]
+    pub const unsafe fn resources_mut(self) -> &'w mut Resources {
+        self.assert_allows_mutable_access();
+        unsafe { &mut (*self.ptr).storages.resources }
+    }
 }
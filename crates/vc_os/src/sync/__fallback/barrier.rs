@@ -121,12 +121,10 @@ impl fmt::Debug for BarrierWaitResult {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::prelude::v1::*;
-    use std::sync::{
-        Arc,
-        atomic::{AtomicUsize, Ordering},
-        mpsc::{channel, TryRecvError},
-    };
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc::{TryRecvError, channel};
     use std::thread;
 
     use super::Barrier;
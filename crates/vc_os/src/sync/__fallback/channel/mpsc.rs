@@ -470,9 +470,9 @@ impl<T> fmt::Debug for Receiver<T> {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloc::vec;
-    use std::prelude::v1::*;
+    use alloc::vec::Vec;
+    use core::time::Duration;
     use std::thread;
-    use std::time::Duration;
 
     use super::{RecvTimeoutError, TryRecvError, TrySendError, channel, sync_channel};
 
@@ -204,15 +204,14 @@ impl<T: UnwindSafe, F: UnwindSafe> UnwindSafe for LazyLock<T, F> {}
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
+    use alloc::string::String;
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use alloc::format;
     use core::ops::DerefMut;
-    use std::format;
-    use std::prelude::v1::*;
-    use std::sync::{
-        Arc,
-        atomic::{AtomicUsize, Ordering},
-    };
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::time::Duration;
     use std::thread;
-    use std::time::Duration;
 
     use super::LazyLock;
     use crate::utils::tests::test_unwind_panic;
@@ -228,7 +227,7 @@ mod tests {
     fn deref_mut_and_default() {
         let mut l = LazyLock::new(|| String::from("abc"));
         let s = l.deref_mut();
-        s.push_str("d");
+        s.push('d');
         assert_eq!(&*l, "abcd");
 
         let d: LazyLock<i32> = LazyLock::default();
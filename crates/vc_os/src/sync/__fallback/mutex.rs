@@ -257,10 +257,12 @@ pub(super) fn guard_lock<'a, T: ?Sized>(guard: &MutexGuard<'a, T>) -> &'a Futex
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::fmt::Debug;
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::sync::{Arc, mpsc::channel};
-    use std::{hint, mem, thread};
+    use alloc::sync::Arc;
+    use core::fmt::Debug;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::{hint, mem};
+    use std::sync::mpsc::channel;
+    use std::thread;
 
     use super::Mutex;
 
@@ -567,12 +567,13 @@ impl<T: ?Sized + fmt::Display> fmt::Display for RwLockWriteGuard<'_, T> {
 /// copy from standard library
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::fmt::Debug;
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::{hint, mem};
     use std::sync::mpsc::channel;
-    use std::sync::Arc;
-    use std::{hint, mem, thread};
-    use std::vec::Vec;
+    use std::thread;
     use super::{RwLock, RwLockWriteGuard, RwLockReadGuard, TryLockError};
 
     #[derive(Eq, PartialEq, Debug)]
@@ -628,7 +629,7 @@ mod tests {
                     seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
                     
                     // Use low bits to decide read/write (approximately 1/N probability for write)
-                    if (seed & ((1 << 16) - 1)) < (65536 / N as u32) {
+                    if (seed & ((1 << 16) - 1)) < (65536 / N) {
                         drop(r.write().unwrap());
                     } else {
                         drop(r.read().unwrap());
@@ -819,7 +820,7 @@ mod tests {
 
         // Wait for a good amount of time so that evil threads go to sleep.
         // Note: this is not strictly necessary...
-        let eternity = std::time::Duration::from_millis(42);
+        let eternity = core::time::Duration::from_millis(42);
         thread::sleep(eternity);
 
         // Once everyone is asleep, set the value to `NEW_VALUE`.
@@ -844,6 +845,11 @@ mod tests {
     }
 
     #[test]
+    #[allow(
+        clippy::needless_lifetimes,
+        clippy::drop_non_drop,
+        reason = "explicitly checks covariance"
+    )]
     fn test_read_guard_covariance() {
         fn do_stuff<'a, 'b>(_: RwLockReadGuard<'_, &'a i32>, _: &'b i32) {}
         let j: i32 = 5;
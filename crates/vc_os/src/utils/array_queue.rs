@@ -435,8 +435,8 @@ impl<T> fmt::Debug for ArrayQueue<T> {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::vec::Vec;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
 
     use super::ArrayQueue;
     use std::thread::scope;
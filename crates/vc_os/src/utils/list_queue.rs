@@ -694,8 +694,8 @@ pub struct PushLockGuard<'a, T>(SpinLockGuard<'a, (*mut Block<T>, usize)>);
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::vec::Vec;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
 
     use super::ListQueue;
     use std::thread::scope;
@@ -49,8 +49,9 @@ pub use spin_lock::{SpinLock, SpinLockGuard};
 #[cfg(all(test, feature = "std"))]
 #[allow(dead_code, reason = "tests")]
 pub(crate) mod tests {
+    use alloc::boxed::Box;
     use core::{any::Any, panic::AssertUnwindSafe, sync::atomic};
-    use std::{boxed::Box, panic, thread};
+    use std::{panic, thread};
 
     pub(crate) fn test_unwind_panic<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
         let prev_hook = panic::take_hook();
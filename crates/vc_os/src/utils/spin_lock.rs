@@ -224,10 +224,12 @@ impl<T: ?Sized + fmt::Display> fmt::Display for SpinLockGuard<'_, T> {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::fmt::Debug;
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::sync::{Arc, mpsc::channel};
-    use std::{hint, mem, thread};
+    use alloc::sync::Arc;
+    use core::fmt::Debug;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::{hint, mem};
+    use std::sync::mpsc::channel;
+    use std::thread;
 
     use super::SpinLock;
 
@@ -401,15 +401,13 @@ impl<'a> ReflectMeta<'a> {
         fn is_any_ident_in_token_stream(idents: &[syn::Ident], token_stream: TokenStream) -> bool {
             for token_tree in token_stream {
                 match token_tree {
-                    proc_macro2::TokenTree::Ident(ident) => {
-                        if idents.contains(&ident) {
-                            return true;
-                        }
+                    proc_macro2::TokenTree::Ident(ident) if idents.contains(&ident) => {
+                        return true;
                     }
-                    proc_macro2::TokenTree::Group(group) => {
-                        if is_any_ident_in_token_stream(idents, group.stream()) {
-                            return true;
-                        }
+                    proc_macro2::TokenTree::Group(group)
+                        if is_any_ident_in_token_stream(idents, group.stream()) =>
+                    {
+                        return true;
                     }
                     _ => {}
                 }
@@ -83,7 +83,7 @@ impl CustomAttributes {
 
     /// Returns an iterator over the stored attributes.
     #[inline]
-    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&TypeId, &dyn Reflect)> {
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (TypeId, &dyn Reflect)> {
         self.attributes.iter().map(|(key, val)| (key, &**val))
     }
 
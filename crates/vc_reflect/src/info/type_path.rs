@@ -573,7 +573,7 @@ mod tests {
                     return Some(&s[0..index]);
                 }
             }
-            return Some(s);
+            Some(s)
         };
 
         let hello = f(s);
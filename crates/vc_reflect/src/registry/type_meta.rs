@@ -208,9 +208,7 @@ impl TypeMeta {
 
     /// An iterator visiting all `TypeId - &dyn TypeTrait` pairs in arbitrary order.
     pub fn trait_iter(&self) -> impl ExactSizeIterator<Item = (TypeId, &dyn TypeTrait)> {
-        self.trait_table
-            .iter()
-            .map(|(key, val)| (*key, val.deref()))
+        self.trait_table.iter().map(|(key, val)| (key, val.deref()))
     }
 
     /// An iterator visiting all `TypeId - &mut dyn TypeTrait` pairs in arbitrary order.
@@ -219,7 +217,7 @@ impl TypeMeta {
     ) -> impl ExactSizeIterator<Item = (TypeId, &mut dyn TypeTrait)> {
         self.trait_table
             .iter_mut()
-            .map(|(key, val)| (*key, val.deref_mut()))
+            .map(|(key, val)| (key, val.deref_mut()))
     }
 }
 
@@ -227,7 +225,7 @@ impl Clone for TypeMeta {
     fn clone(&self) -> Self {
         let mut new_map = TypeIdMap::with_capacity(self.trait_count());
         for (id, type_trait) in self.trait_table.iter() {
-            new_map.insert(*id, (**type_trait).clone_type_trait());
+            new_map.insert(id, (**type_trait).clone_type_trait());
         }
 
         Self {
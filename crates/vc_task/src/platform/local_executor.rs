@@ -205,7 +205,7 @@ mod tests {
         let ex = LocalExecutor::new();
         let task = ex.spawn(async { 42 });
 
-        let result = block_on(ex.run(async { task.await }));
+        let result = block_on(ex.run(task));
         assert_eq!(result, 42);
     }
 
@@ -249,7 +249,7 @@ mod tests {
             inner_result * 2
         });
 
-        let result = block_on(ex.run(async { outer_task.await }));
+        let result = block_on(ex.run(outer_task));
         assert_eq!(result, 200);
     }
 }
@@ -0,0 +1,351 @@
+//! Growable bit set over dense `usize` indices.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+// -----------------------------------------------------------------------------
+// FixedBitSet
+
+type Block = usize;
+
+const BITS: usize = Block::BITS as usize;
+
+/// A set of small integers stored as a bit vector.
+///
+/// Intended for dense ids such as component ids, where set operations
+/// (union, intersection, disjointness) are much cheaper than with hash sets.
+///
+/// The set grows on demand: [`insert`](Self::insert) and
+/// [`union_with`](Self::union_with) extend the storage with zeroed blocks,
+/// while all queries treat bits beyond the storage as unset. Two sets with the
+/// same bits are equal regardless of how much storage they have allocated.
+///
+/// ```
+/// use vc_utils::extra::FixedBitSet;
+///
+/// let mut a = FixedBitSet::new();
+/// a.insert(1);
+/// a.insert(200);
+///
+/// let mut b = FixedBitSet::new();
+/// b.insert(2);
+/// assert!(a.is_disjoint(&b));
+///
+/// b.insert(200);
+/// assert!(!a.is_disjoint(&b));
+///
+/// a.union_with(&b);
+/// assert_eq!(a.ones().collect::<Vec<_>>(), [1, 2, 200]);
+/// ```
+#[derive(Clone, Default)]
+pub struct FixedBitSet {
+    blocks: Vec<Block>,
+}
+
+impl FixedBitSet {
+    /// Creates an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { blocks: Vec::new() }
+    }
+
+    /// Creates an empty set able to hold bits `0..bits` without reallocating.
+    #[inline]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            blocks: alloc::vec![0; bits.div_ceil(BITS)],
+        }
+    }
+
+    /// Returns the number of bits the set can hold without growing.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.blocks.len() * BITS
+    }
+
+    /// Grows the set so it can hold bits `0..bits`, zero-extending the storage.
+    #[inline]
+    pub fn grow(&mut self, bits: usize) {
+        let blocks = bits.div_ceil(BITS);
+        if blocks > self.blocks.len() {
+            self.blocks.resize(blocks, 0);
+        }
+    }
+
+    /// Removes all bits, keeping the allocated storage.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.blocks.fill(0);
+    }
+
+    /// Returns `true` if no bit is set.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|&block| block == 0)
+    }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.count_ones() as usize)
+            .sum()
+    }
+
+    /// Sets `bit`, growing the set if needed.
+    ///
+    /// Returns `true` if the bit was not set before.
+    #[inline]
+    pub fn insert(&mut self, bit: usize) -> bool {
+        self.grow(bit + 1);
+        let block = &mut self.blocks[bit / BITS];
+        let mask = 1 << (bit % BITS);
+        let inserted = *block & mask == 0;
+        *block |= mask;
+        inserted
+    }
+
+    /// Unsets `bit`.
+    ///
+    /// Returns `true` if the bit was set before.
+    #[inline]
+    pub fn remove(&mut self, bit: usize) -> bool {
+        match self.blocks.get_mut(bit / BITS) {
+            Some(block) => {
+                let mask = 1 << (bit % BITS);
+                let removed = *block & mask != 0;
+                *block &= !mask;
+                removed
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if `bit` is set.
+    #[inline]
+    pub fn contains(&self, bit: usize) -> bool {
+        self.blocks
+            .get(bit / BITS)
+            .is_some_and(|block| block & (1 << (bit % BITS)) != 0)
+    }
+
+    /// Sets every bit that is set in `other`, growing the set if needed.
+    pub fn union_with(&mut self, other: &FixedBitSet) {
+        if other.blocks.len() > self.blocks.len() {
+            self.blocks.resize(other.blocks.len(), 0);
+        }
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block |= *other;
+        }
+    }
+
+    /// Unsets every bit that is not set in `other`.
+    pub fn intersect_with(&mut self, other: &FixedBitSet) {
+        let shared = self.blocks.len().min(other.blocks.len());
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block &= *other;
+        }
+        self.blocks[shared..].fill(0);
+    }
+
+    /// Unsets every bit that is set in `other`.
+    pub fn difference_with(&mut self, other: &FixedBitSet) {
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block &= !*other;
+        }
+    }
+
+    /// Returns `true` if the two sets have no bit in common.
+    #[inline]
+    pub fn is_disjoint(&self, other: &FixedBitSet) -> bool {
+        self.blocks
+            .iter()
+            .zip(&other.blocks)
+            .all(|(a, b)| a & b == 0)
+    }
+
+    /// Returns `true` if every bit set in `self` is also set in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &FixedBitSet) -> bool {
+        let shared = self.blocks.len().min(other.blocks.len());
+        self.blocks[..shared]
+            .iter()
+            .zip(&other.blocks)
+            .all(|(a, b)| a & !b == 0)
+            && self.blocks[shared..].iter().all(|&block| block == 0)
+    }
+
+    /// Iterates over the set bits in ascending order.
+    #[inline]
+    pub fn ones(&self) -> Ones<'_> {
+        Ones {
+            blocks: self.blocks.iter(),
+            current: 0,
+            base: 0,
+        }
+    }
+
+    /// The blocks without trailing zero blocks, used for comparison and hashing.
+    #[inline]
+    fn trimmed(&self) -> &[Block] {
+        let len = self
+            .blocks
+            .iter()
+            .rposition(|&block| block != 0)
+            .map_or(0, |index| index + 1);
+        &self.blocks[..len]
+    }
+}
+
+impl PartialEq for FixedBitSet {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for FixedBitSet {}
+
+impl Hash for FixedBitSet {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trimmed().hash(state);
+    }
+}
+
+impl fmt::Debug for FixedBitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ones()).finish()
+    }
+}
+
+impl Extend<usize> for FixedBitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for bit in iter {
+            self.insert(bit);
+        }
+    }
+}
+
+impl FromIterator<usize> for FixedBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Ones
+
+/// An iterator over the set bits of a [`FixedBitSet`], in ascending order.
+///
+/// Created by [`FixedBitSet::ones`].
+#[derive(Clone)]
+pub struct Ones<'a> {
+    blocks: core::slice::Iter<'a, Block>,
+    current: Block,
+    base: usize,
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.current = *self.blocks.next()?;
+            self.base += BITS;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.current &= self.current - 1;
+        Some(self.base - BITS + bit)
+    }
+}
+
+impl core::iter::FusedIterator for Ones<'_> {}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{BITS, FixedBitSet};
+
+    #[test]
+    fn disjoint_and_overlapping() {
+        let a: FixedBitSet = [1, 3, 5].into_iter().collect();
+        let b: FixedBitSet = [0, 2, 4, 1000].into_iter().collect();
+        let c: FixedBitSet = [5, 1000].into_iter().collect();
+
+        assert!(a.is_disjoint(&b));
+        assert!(b.is_disjoint(&a));
+        assert!(!a.is_disjoint(&c));
+        assert!(!b.is_disjoint(&c));
+
+        let mut union = a.clone();
+        union.union_with(&c);
+        assert_eq!(union.ones().collect::<Vec<_>>(), [1, 3, 5, 1000]);
+
+        let mut intersection = b.clone();
+        intersection.intersect_with(&c);
+        assert_eq!(intersection.ones().collect::<Vec<_>>(), [1000]);
+
+        let mut difference = union.clone();
+        difference.difference_with(&c);
+        assert_eq!(difference.ones().collect::<Vec<_>>(), [1, 3]);
+        assert!(a.is_subset(&union));
+        assert!(!union.is_subset(&a));
+    }
+
+    #[test]
+    fn grow_zero_extends() {
+        let mut set = FixedBitSet::new();
+        assert!(!set.contains(10_000));
+        assert!(!set.remove(10_000));
+
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        set.grow(5 * BITS);
+        assert_eq!(set.capacity(), 5 * BITS);
+        assert_eq!(set.ones().collect::<Vec<_>>(), [3]);
+
+        // Storage size does not affect equality.
+        let small: FixedBitSet = [3].into_iter().collect();
+        assert_eq!(set, small);
+
+        let mut intersection = set.clone();
+        intersection.intersect_with(&FixedBitSet::new());
+        assert!(intersection.is_empty());
+    }
+
+    #[test]
+    fn ones_over_sparse_bits() {
+        let bits = [0, BITS - 1, BITS, 3 * BITS + 7, 100_000];
+        let set: FixedBitSet = bits.into_iter().collect();
+        assert_eq!(set.ones().collect::<Vec<_>>(), bits);
+        assert_eq!(set.count_ones(), bits.len());
+    }
+
+    #[test]
+    fn large_ranges() {
+        const N: usize = 1 << 16;
+
+        let even: FixedBitSet = (0..N).step_by(2).collect();
+        let odd: FixedBitSet = (1..N).step_by(2).collect();
+        assert!(even.is_disjoint(&odd));
+
+        let mut all = even.clone();
+        all.union_with(&odd);
+        assert_eq!(all.count_ones(), N);
+        assert!(all.ones().eq(0..N));
+
+        let mut none = even.clone();
+        none.intersect_with(&odd);
+        assert!(none.is_empty());
+    }
+}
@@ -5,7 +5,9 @@
 
 mod array_deque;
 mod block_list;
+mod fixed_bit_set;
 mod page_pool;
+mod small_str;
 mod typeid_map;
 
 // -----------------------------------------------------------------------------
@@ -13,5 +15,7 @@ mod typeid_map;
 
 pub use array_deque::ArrayDeque;
 pub use block_list::BlockList;
+pub use fixed_bit_set::{FixedBitSet, Ones};
 pub use page_pool::PagePool;
+pub use small_str::SmallStr;
 pub use typeid_map::TypeIdMap;
@@ -0,0 +1,216 @@
+//! Inline string with a fixed byte capacity, no heap allocation required.
+#![expect(unsafe_code, reason = "skip repeated UTF-8 validation")]
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+// -----------------------------------------------------------------------------
+// SmallStr
+
+/// A string of up to `N` bytes stored inline.
+///
+/// This is meant for names provided at runtime, such as the names of dynamic
+/// components, in environments without a global allocator.
+///
+/// Longer inputs are truncated and end with [`SmallStr::MARKER`], the cut
+/// always falls on a `char` boundary, so the content stays valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::extra::SmallStr;
+///
+/// let name = SmallStr::<8>::from("Health");
+/// assert_eq!(&*name, "Health");
+///
+/// let name = SmallStr::<8>::from("Velocity3d");
+/// assert_eq!(&*name, "Veloc…");
+/// assert!(name.is_truncated());
+/// ```
+#[derive(Clone, Copy)]
+pub struct SmallStr<const N: usize> {
+    len: usize,
+    truncated: bool,
+    bytes: [u8; N],
+}
+
+impl<const N: usize> SmallStr<N> {
+    /// The marker appended to truncated strings.
+    pub const MARKER: &'static str = "…";
+
+    /// Creates an empty `SmallStr`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            truncated: false,
+            bytes: [0; N],
+        }
+    }
+
+    /// Returns the content as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `bytes[..len]` is always copied from a `str` and
+        // cut at `char` boundaries, so it is valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+
+    /// Returns `true` if the input did not fit and was truncated.
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Appends `s` to the content. Returns `false` if it does not fit.
+    fn push_str(&mut self, s: &str) -> bool {
+        let end = self.len + s.len();
+        if end > N {
+            return false;
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        true
+    }
+}
+
+/// Returns the largest `char` boundary of `s` not greater than `index`.
+#[inline]
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+// -----------------------------------------------------------------------------
+// Traits
+
+impl<const N: usize> Default for SmallStr<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<&str> for SmallStr<N> {
+    /// Copies `value`, truncating it with [`SmallStr::MARKER`] if it is longer than `N` bytes.
+    ///
+    /// If `N` is too small to hold even the marker, the content is cut without it.
+    fn from(value: &str) -> Self {
+        let mut this = Self::new();
+        if this.push_str(value) {
+            return this;
+        }
+
+        this.truncated = true;
+        let marker = Self::MARKER;
+        let budget = N.saturating_sub(marker.len());
+        this.push_str(&value[..floor_char_boundary(value, budget)]);
+        if !this.push_str(marker) {
+            this.len = 0;
+            this.push_str(&value[..floor_char_boundary(value, N)]);
+        }
+        this
+    }
+}
+
+impl<const N: usize> Deref for SmallStr<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for SmallStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for SmallStr<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for SmallStr<N> {}
+
+impl<const N: usize> PartialEq<str> for SmallStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> Hash for SmallStr<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> fmt::Display for SmallStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for SmallStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::SmallStr;
+
+    #[test]
+    fn ascii_fits() {
+        let name = SmallStr::<8>::from("Position");
+        assert_eq!(name, *"Position");
+        assert!(!name.is_truncated());
+        assert_eq!(name.to_string(), "Position");
+        assert_eq!(SmallStr::<8>::from(""), SmallStr::new());
+    }
+
+    #[test]
+    fn overflow_is_truncated_with_marker() {
+        let name = SmallStr::<8>::from("Positions");
+        assert!(name.is_truncated());
+        assert_eq!(&*name, "Posit…");
+        assert!(name.len() <= 8);
+    }
+
+    #[test]
+    fn multibyte_never_split() {
+        // Each `é` is 2 bytes, 5 of them take 10 bytes.
+        let name = SmallStr::<10>::from("ééééé");
+        assert_eq!(&*name, "ééééé");
+
+        // 7 bytes are left for content, the 4th `é` would end at byte 8.
+        let name = SmallStr::<10>::from("éééééé");
+        assert_eq!(&*name, "ééé…");
+
+        // A 3 byte `…` does not fit, so the content is cut without it.
+        let name = SmallStr::<2>::from("aé");
+        assert_eq!(&*name, "a");
+        assert!(name.is_truncated());
+    }
+}
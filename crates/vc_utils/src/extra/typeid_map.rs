@@ -176,19 +176,19 @@ impl<V> TypeIdMap<V> {
 
     /// An iterator visiting all key-value pairs in arbitrary order.
     ///
-    /// The iterator element type is `(&'a K, &'a V)`.
+    /// The iterator element type is `(TypeId, &'a V)`.
     #[inline]
-    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&TypeId, &V)> {
-        self.0.iter()
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (TypeId, &V)> {
+        self.0.iter().map(|(&type_id, v)| (type_id, v))
     }
 
     /// An iterator visiting all key-value pairs in arbitrary order,
     /// with mutable references to the values.
     ///
-    /// The iterator element type is `(&'a K, &'a mut V)`.
+    /// The iterator element type is `(TypeId, &'a mut V)`.
     #[inline]
-    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = (&TypeId, &mut V)> {
-        self.0.iter_mut()
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = (TypeId, &mut V)> {
+        self.0.iter_mut().map(|(&type_id, v)| (type_id, v))
     }
 
     /// An iterator visiting all values in arbitrary order.
@@ -238,3 +238,43 @@ impl<T: Debug> Debug for TypeIdMap<T> {
         Debug::fmt(&self.0, f)
     }
 }
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::any::TypeId;
+
+    use super::TypeIdMap;
+
+    struct A;
+    struct B;
+
+    #[test]
+    fn typed_lookup() {
+        let mut map = TypeIdMap::new();
+        map.insert(TypeId::of::<A>(), 1);
+
+        assert!(map.contains_type::<A>());
+        assert!(!map.contains_type::<B>());
+        assert_eq!(map.get_type::<A>(), Some(&1));
+        assert_eq!(map.get_type::<B>(), None);
+    }
+
+    #[test]
+    fn iter_yields_type_ids() {
+        let mut map = TypeIdMap::new();
+        map.insert_type::<A>(1);
+        map.insert_type::<B>(2);
+
+        for (_, v) in map.iter_mut() {
+            *v *= 10;
+        }
+
+        let mut entries: Vec<_> = map.iter().map(|(type_id, &v)| (type_id, v)).collect();
+        entries.sort_by_key(|&(_, v)| v);
+        assert_eq!(entries, [(TypeId::of::<A>(), 10), (TypeId::of::<B>(), 20)]);
+    }
+}
@@ -890,6 +890,12 @@ where
 
     /// Attempts to get mutable references to N values in the map at once.
     ///
+    /// Missing keys yield `None` in their slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any keys are overlapping.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -903,6 +909,15 @@ where
     /// let result = map.get_disjoint_mut(["foo", "bar"]);
     ///
     /// assert_eq!(result, [Some(&mut 0), Some(&mut 1)]);
+    ///
+    /// // Swap two values in place.
+    /// let [Some(foo), Some(baz)] = map.get_disjoint_mut(["foo", "baz"]) else {
+    ///     unreachable!()
+    /// };
+    /// core::mem::swap(foo, baz);
+    ///
+    /// assert_eq!(map["foo"], 2);
+    /// assert_eq!(map["baz"], 0);
     /// ```
     #[inline(always)]
     pub fn get_disjoint_mut<Q, const N: usize>(&mut self, ks: [&Q; N]) -> [Option<&'_ mut V>; N]
@@ -915,6 +930,10 @@ where
     /// Attempts to get mutable references to N values in the map at once,
     /// with immutable references to the corresponding keys.
     ///
+    /// # Panics
+    ///
+    /// Panics if any keys are overlapping.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -819,6 +819,12 @@ where
 
     /// Attempts to get mutable references to N values in the map at once.
     ///
+    /// Missing keys yield `None` in their slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any keys are overlapping.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -844,6 +850,10 @@ where
     /// Attempts to get mutable references to N values in the map at once,
     /// with immutable references to the corresponding keys.
     ///
+    /// # Panics
+    ///
+    /// Panics if any keys are overlapping.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -62,12 +62,25 @@ impl<V: Hash + Eq + Clone> Hashed<V> {
 impl<V: Eq + Clone> Hashed<V> {
     /// Create a `Hashed` through given value and hash value.
     ///
+    /// This avoids recomputing a hash that is already known, e.g. one taken
+    /// from another `Hashed` via [`Hashed::hash`].
+    ///
+    /// `hash` must be the hash of `value` computed by the same [`BuildHasher`]
+    /// as the other keys it is compared with. Otherwise equal values compare
+    /// unequal and map lookups miss. This is a logic error, not undefined behavior.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use vc_utils::hash::Hashed;
+    /// use vc_utils::hash::{Hashed, PreHashMap};
     ///
-    /// let hashed = Hashed::with_hash(1, 61671341508);
+    /// let hashed = Hashed::new("name");
+    /// let rebuilt = Hashed::with_hash(*hashed.value(), hashed.hash());
+    /// assert_eq!(hashed, rebuilt);
+    ///
+    /// let mut map = PreHashMap::default();
+    /// map.insert(hashed, 1);
+    /// assert_eq!(map.get(&rebuilt), Some(&1));
     /// ```
     #[inline(always)]
     pub const fn with_hash(value: V, hash: u64) -> Self {
@@ -88,6 +101,21 @@ impl<V: Eq + Clone> Hashed<V> {
     pub const fn hash(&self) -> u64 {
         self.hash
     }
+
+    /// Return a reference to the hashed value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_utils::hash::Hashed;
+    ///
+    /// let hashed = Hashed::new(1);
+    /// assert_eq!(*hashed.value(), 1);
+    /// ```
+    #[inline(always)]
+    pub const fn value(&self) -> &V {
+        &self.value
+    }
 }
 
 impl<V> Hash for Hashed<V> {
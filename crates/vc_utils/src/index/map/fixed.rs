@@ -1329,6 +1329,35 @@ impl<K, V, S> IndexMap<K, V, S> {
         self.0.retain(keep);
     }
 
+    /// Remove all key-value pairs where `pred` returns `true`, by shifting
+    /// the remaining elements, and return the number of removed pairs.
+    ///
+    /// Unlike calling [`shift_remove`](Self::shift_remove) for each match,
+    /// the survivors are compacted in a single pass and keep their order.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vc_utils::index::IndexMap;
+    ///
+    /// let mut map: IndexMap<u32, char> = (0..10).zip('a'..).collect();
+    /// let removed = map.shift_remove_where(|key, _| key % 2 == 1);
+    ///
+    /// assert_eq!(removed, 5);
+    /// assert!(map.keys().eq(&[0, 2, 4, 6, 8]));
+    /// assert!(map.values().eq(&['a', 'c', 'e', 'g', 'i']));
+    /// ```
+    pub fn shift_remove_where<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let len = self.0.len();
+        self.0.retain(|key, value| !pred(key, value));
+        len - self.0.len()
+    }
+
     /// Sort the map's key-value pairs by the default ordering of the keys.
     ///
     /// This is a stable sort -- but equivalent keys should not normally coexist in
@@ -17,12 +17,11 @@ mod unsafe_deref;
 pub mod extra;
 pub mod hash;
 pub mod index;
+pub mod vec;
 
 // -----------------------------------------------------------------------------
 // Top-level exports
 
-pub use fastvec as vec;
-
 pub use default::default;
 pub use unsafe_deref::UnsafeCellDeref;
 
@@ -0,0 +1,264 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+// -----------------------------------------------------------------------------
+// ArenaId
+
+/// A stable handle to a value stored in an [`Arena`].
+///
+/// The handle carries the generation of its slot, so it stops resolving once
+/// the value is removed, even if the slot is later reused by another value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArenaId {
+    index: u32,
+    generation: u32,
+}
+
+impl ArenaId {
+    /// Returns the slot index of this handle.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self.index as usize
+    }
+
+    /// Returns the generation of this handle.
+    #[inline(always)]
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+impl fmt::Debug for ArenaId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ArenaId({}v{})", self.index, self.generation)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Arena
+
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+/// A `Vec`-backed container handing out stable [`ArenaId`]s.
+///
+/// Removed slots are recycled through a free list, and each reuse bumps the
+/// slot's generation, so stale ids never alias a newer value.
+///
+/// # Examples
+///
+/// ```
+/// use vc_utils::vec::Arena;
+///
+/// let mut arena = Arena::new();
+/// let a = arena.insert("a");
+/// assert_eq!(arena.remove(a), Some("a"));
+///
+/// let b = arena.insert("b");
+/// assert_eq!(a.index(), b.index());
+/// assert_eq!(arena.get(a), None);
+/// assert_eq!(arena.get(b), Some(&"b"));
+/// ```
+pub struct Arena<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+}
+
+impl<T> Default for Arena<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Arena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Arena<T> {
+    /// Creates an empty `Arena`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Creates an empty `Arena` with space for at least `capacity` values.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns the number of values in the arena.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns `true` if the arena contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts a value, returning its id.
+    ///
+    /// A previously removed slot is reused if available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of slots exceeds `u32::MAX`.
+    pub fn insert(&mut self, value: T) -> ArenaId {
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index as usize];
+            debug_assert!(slot.value.is_none());
+            slot.value = Some(value);
+            ArenaId {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            let index = u32::try_from(self.slots.len()).expect("too many arena slots");
+            self.slots.push(Slot {
+                generation: 0,
+                value: Some(value),
+            });
+            ArenaId {
+                index,
+                generation: 0,
+            }
+        }
+    }
+
+    /// Returns `true` if `id` refers to a value in the arena.
+    #[inline]
+    pub fn contains(&self, id: ArenaId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Returns a reference to the value of `id`,
+    /// or `None` if it was removed.
+    #[inline]
+    pub fn get(&self, id: ArenaId) -> Option<&T> {
+        self.slots
+            .get(id.index())
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    /// Returns a mutable reference to the value of `id`,
+    /// or `None` if it was removed.
+    #[inline]
+    pub fn get_mut(&mut self, id: ArenaId) -> Option<&mut T> {
+        self.slots
+            .get_mut(id.index())
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    /// Removes and returns the value of `id`,
+    /// or `None` if it was already removed.
+    ///
+    /// The slot's generation is bumped, invalidating `id` and all its copies.
+    pub fn remove(&mut self, id: ArenaId) -> Option<T> {
+        let slot = self.slots.get_mut(id.index())?;
+        if slot.generation != id.generation {
+            return None;
+        }
+        let value = slot.value.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id.index);
+        Some(value)
+    }
+
+    /// Removes all values, invalidating every id handed out so far.
+    pub fn clear(&mut self) {
+        self.free.clear();
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.value.take().is_some() {
+                slot.generation = slot.generation.wrapping_add(1);
+            }
+            self.free.push(index as u32);
+        }
+    }
+
+    /// Iterates over the ids and values in the arena, in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (ArenaId, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let id = ArenaId {
+                index: index as u32,
+                generation: slot.generation,
+            };
+            slot.value.as_ref().map(|value| (id, value))
+        })
+    }
+
+    /// Iterates mutably over the ids and values in the arena, in slot order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (ArenaId, &mut T)> {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| {
+                let id = ArenaId {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                slot.value.as_mut().map(|value| (id, value))
+            })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Arena;
+
+    #[test]
+    fn reused_slot_bumps_generation() {
+        let mut arena = Arena::new();
+        let a = arena.insert(1);
+        let b = arena.insert(2);
+        assert_eq!(arena.len(), 2);
+
+        assert_eq!(arena.remove(a), Some(1));
+        assert_eq!(arena.remove(a), None);
+        assert_eq!(arena.len(), 1);
+
+        let c = arena.insert(3);
+        assert_eq!(c.index(), a.index());
+        assert_ne!(c.generation(), a.generation());
+
+        assert_eq!(arena.get(a), None);
+        assert_eq!(arena.get(b), Some(&2));
+        assert_eq!(arena.get(c), Some(&3));
+        assert_eq!(arena.len(), 2);
+    }
+
+    #[test]
+    fn clear_invalidates_ids() {
+        let mut arena = Arena::new();
+        let a = arena.insert('a');
+        arena.insert('b');
+        arena.clear();
+        assert!(arena.is_empty());
+        assert!(!arena.contains(a));
+
+        let c = arena.insert('c');
+        *arena.get_mut(c).unwrap() = 'd';
+        let values: Vec<_> = arena.iter().map(|(_, v)| *v).collect();
+        assert_eq!(values, ['d']);
+    }
+}
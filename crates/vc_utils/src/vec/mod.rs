@@ -0,0 +1,7 @@
+//! Re-exports *[fastvec]*, provide a generational [`Arena`].
+
+pub use fastvec::*;
+
+mod arena;
+
+pub use arena::{Arena, ArenaId};